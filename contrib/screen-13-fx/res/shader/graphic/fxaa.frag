@@ -0,0 +1,169 @@
+#version 460 core
+
+// Based on the FXAA 3.11 algorithm by Timothy Lottes
+
+#define ITERATIONS 12
+
+layout(push_constant) uniform PushConstants {
+    layout(offset = 0) float edge_threshold;
+    layout(offset = 4) float edge_threshold_min;
+    layout(offset = 8) float subpixel_quality;
+} push_constants;
+
+layout(set = 0, binding = 0) uniform sampler2D src_image_sampler_lle;
+
+layout(location = 0) in vec2 texcoord;
+
+layout(location = 0) out vec4 color;
+
+float luma(vec2 uv) {
+    return dot(textureLod(src_image_sampler_lle, uv, 0).rgb, vec3(0.299, 0.587, 0.114));
+}
+
+float luma(vec2 uv, ivec2 offset) {
+    return dot(
+        textureLodOffset(src_image_sampler_lle, uv, 0, offset).rgb,
+        vec3(0.299, 0.587, 0.114)
+    );
+}
+
+float quality(int i) {
+    return i < 5 ? 1.0 : i == 5 ? 1.5 : i < 10 ? 2.0 : i == 10 ? 4.0 : 8.0;
+}
+
+void main()
+{
+    vec2 texel = 1.0 / vec2(textureSize(src_image_sampler_lle, 0));
+    vec2 uv = texcoord;
+
+    vec4 center = textureLod(src_image_sampler_lle, uv, 0);
+    float luma_c = dot(center.rgb, vec3(0.299, 0.587, 0.114));
+    float luma_n = luma(uv, ivec2(0, -1));
+    float luma_s = luma(uv, ivec2(0, 1));
+    float luma_e = luma(uv, ivec2(1, 0));
+    float luma_w = luma(uv, ivec2(-1, 0));
+
+    float luma_min = min(luma_c, min(min(luma_n, luma_s), min(luma_e, luma_w)));
+    float luma_max = max(luma_c, max(max(luma_n, luma_s), max(luma_e, luma_w)));
+    float luma_range = luma_max - luma_min;
+
+    // Flat regions are left untouched
+    if (luma_range < max(push_constants.edge_threshold_min,
+                         luma_max * push_constants.edge_threshold)) {
+        color = center;
+        return;
+    }
+
+    float luma_nw = luma(uv, ivec2(-1, -1));
+    float luma_ne = luma(uv, ivec2(1, -1));
+    float luma_sw = luma(uv, ivec2(-1, 1));
+    float luma_se = luma(uv, ivec2(1, 1));
+
+    float luma_ns = luma_n + luma_s;
+    float luma_ew = luma_e + luma_w;
+    float luma_w_corners = luma_nw + luma_sw;
+    float luma_e_corners = luma_ne + luma_se;
+    float luma_n_corners = luma_nw + luma_ne;
+    float luma_s_corners = luma_sw + luma_se;
+
+    // Decide whether the local edge is horizontal or vertical
+    float edge_horizontal = abs(-2.0 * luma_w + luma_w_corners)
+                          + abs(-2.0 * luma_c + luma_ns) * 2.0
+                          + abs(-2.0 * luma_e + luma_e_corners);
+    float edge_vertical = abs(-2.0 * luma_n + luma_n_corners)
+                        + abs(-2.0 * luma_c + luma_ew) * 2.0
+                        + abs(-2.0 * luma_s + luma_s_corners);
+    bool is_horizontal = edge_horizontal >= edge_vertical;
+
+    // Choose the side of the edge with the steepest gradient
+    float luma1 = is_horizontal ? luma_n : luma_w;
+    float luma2 = is_horizontal ? luma_s : luma_e;
+    float gradient1 = luma1 - luma_c;
+    float gradient2 = luma2 - luma_c;
+    bool is1_steepest = abs(gradient1) >= abs(gradient2);
+    float gradient_scaled = 0.25 * max(abs(gradient1), abs(gradient2));
+
+    float step_len = is_horizontal ? texel.y : texel.x;
+    float luma_local_avg;
+
+    if (is1_steepest) {
+        step_len = -step_len;
+        luma_local_avg = 0.5 * (luma1 + luma_c);
+    } else {
+        luma_local_avg = 0.5 * (luma2 + luma_c);
+    }
+
+    vec2 edge_uv = uv;
+
+    if (is_horizontal) {
+        edge_uv.y += step_len * 0.5;
+    } else {
+        edge_uv.x += step_len * 0.5;
+    }
+
+    // Walk along the edge in both directions until its ends are found
+    vec2 offset = is_horizontal ? vec2(texel.x, 0.0) : vec2(0.0, texel.y);
+    vec2 uv1 = edge_uv - offset;
+    vec2 uv2 = edge_uv + offset;
+
+    float luma_end1 = luma(uv1) - luma_local_avg;
+    float luma_end2 = luma(uv2) - luma_local_avg;
+    bool reached1 = abs(luma_end1) >= gradient_scaled;
+    bool reached2 = abs(luma_end2) >= gradient_scaled;
+
+    if (!reached1) {
+        uv1 -= offset;
+    }
+
+    if (!reached2) {
+        uv2 += offset;
+    }
+
+    for (int i = 2; i < ITERATIONS && !(reached1 && reached2); i++) {
+        if (!reached1) {
+            luma_end1 = luma(uv1) - luma_local_avg;
+            reached1 = abs(luma_end1) >= gradient_scaled;
+        }
+
+        if (!reached2) {
+            luma_end2 = luma(uv2) - luma_local_avg;
+            reached2 = abs(luma_end2) >= gradient_scaled;
+        }
+
+        if (!reached1) {
+            uv1 -= offset * quality(i);
+        }
+
+        if (!reached2) {
+            uv2 += offset * quality(i);
+        }
+    }
+
+    float distance1 = is_horizontal ? uv.x - uv1.x : uv.y - uv1.y;
+    float distance2 = is_horizontal ? uv2.x - uv.x : uv2.y - uv.y;
+    bool is_direction1 = distance1 < distance2;
+    float distance_final = min(distance1, distance2);
+    float edge_len = distance1 + distance2;
+
+    // Only offset when the luma variation at the nearest end agrees with the center
+    bool is_luma_c_smaller = luma_c < luma_local_avg;
+    bool is_correct_variation = ((is_direction1 ? luma_end1 : luma_end2) < 0.0) != is_luma_c_smaller;
+    float pixel_offset = is_correct_variation ? 0.5 - distance_final / edge_len : 0.0;
+
+    // Subpixel anti-aliasing for thin or single-pixel features
+    float luma_avg = (2.0 * (luma_ns + luma_ew) + luma_w_corners + luma_e_corners) / 12.0;
+    float subpixel1 = clamp(abs(luma_avg - luma_c) / luma_range, 0.0, 1.0);
+    float subpixel2 = (-2.0 * subpixel1 + 3.0) * subpixel1 * subpixel1;
+    float subpixel_offset = subpixel2 * subpixel2 * push_constants.subpixel_quality;
+
+    float final_offset = max(pixel_offset, subpixel_offset);
+    vec2 final_uv = uv;
+
+    if (is_horizontal) {
+        final_uv.y += final_offset * step_len;
+    } else {
+        final_uv.x += final_offset * step_len;
+    }
+
+    color = textureLod(src_image_sampler_lle, final_uv, 0);
+}
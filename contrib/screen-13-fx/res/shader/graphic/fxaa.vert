@@ -0,0 +1,10 @@
+#version 460 core
+
+#include "../inc/quad.glsl"
+
+layout(location = 0) out vec2 texcoord_out;
+
+void main() {
+    texcoord_out = vertex_tex();
+    gl_Position = vec4(vertex_pos(), 0, 1);
+}
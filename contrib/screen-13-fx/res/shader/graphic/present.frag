@@ -8,7 +8,5 @@ layout(location = 0) out vec4 color;
 
 void main()
 {
-    vec3 image_sample = texture(image_sampler_llr, uv).rgb;
-
-    color = vec4(image_sample, 1.0);
+    color = texture(image_sampler_llr, uv);
 }
@@ -1,9 +1,9 @@
 use {
+    super::spirv,
     anyhow::Context,
     bmfont::BMFont,
     bytemuck::{cast, cast_slice},
     glam::{vec3, Mat4},
-    inline_spirv::include_spirv,
     screen_13::prelude::*,
     std::sync::Arc,
 };
@@ -42,20 +42,17 @@ impl BitmapFont {
                 device,
                 GraphicPipelineInfo::new().blend(BlendMode::ALPHA),
                 [
-                    Shader::new_vertex(
-                        include_spirv!("res/shader/graphic/font.vert", vert).as_slice(),
+                    Shader::new_vertex(spirv::FONT_VERT),
+                    Shader::new_fragment(spirv::FONT_FRAG).specialization_info(
+                        SpecializationInfo::new(
+                            [vk::SpecializationMapEntry {
+                                constant_id: 0,
+                                offset: 0,
+                                size: 4,
+                            }],
+                            num_pages.to_ne_bytes(),
+                        ),
                     ),
-                    Shader::new_fragment(
-                        include_spirv!("res/shader/graphic/font.frag", frag).as_slice(),
-                    )
-                    .specialization_info(SpecializationInfo::new(
-                        [vk::SpecializationMapEntry {
-                            constant_id: 0,
-                            offset: 0,
-                            size: 4,
-                        }],
-                        num_pages.to_ne_bytes(),
-                    )),
                 ],
             )
             .context("Unable to create bitmap font pipeline")?,
@@ -148,9 +145,84 @@ impl BitmapFont {
         scale: f32,
         scissor: Option<(i32, i32, u32, u32)>,
     ) {
+        let text = text.as_ref();
+        let vertex_buf_len = 120 * text.chars().count() as vk::DeviceSize;
+
+        self.record(
+            graph,
+            image,
+            x,
+            y,
+            color,
+            scale,
+            scissor,
+            vertex_buf_len,
+            |font, vertex_buf| font.tessellate(text, vertex_buf),
+        );
+    }
+
+    /// Lays out the given text so that it may be printed many times without parsing or
+    /// tessellating the glyphs again.
+    ///
+    /// The result is only valid for this font; it does not depend on position, color or scale.
+    pub fn layout(&self, text: impl AsRef<str>) -> BitmapText {
+        let text = text.as_ref();
+        let mut vertices = vec![0; 120 * text.chars().count()];
+        let vertex_count = self.tessellate(text, &mut vertices);
+        vertices.truncate(20 * vertex_count as usize);
+
+        BitmapText { vertices }
+    }
+
+    /// Prints text previously laid out by [`BitmapFont::layout`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn print_text(
+        &mut self,
+        graph: &mut RenderGraph,
+        image: impl Into<AnyImageNode>,
+        x: f32,
+        y: f32,
+        color: impl Into<BitmapGlyphColor>,
+        text: &BitmapText,
+        scale: f32,
+        scissor: Option<(i32, i32, u32, u32)>,
+    ) {
+        self.record(
+            graph,
+            image,
+            x,
+            y,
+            color,
+            scale,
+            scissor,
+            text.vertices.len() as _,
+            |_, vertex_buf| {
+                vertex_buf.copy_from_slice(&text.vertices);
+
+                text.vertex_count()
+            },
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn record(
+        &mut self,
+        graph: &mut RenderGraph,
+        image: impl Into<AnyImageNode>,
+        x: f32,
+        y: f32,
+        color: impl Into<BitmapGlyphColor>,
+        scale: f32,
+        scissor: Option<(i32, i32, u32, u32)>,
+        vertex_buf_len: vk::DeviceSize,
+        write_vertices: impl FnOnce(&Self, &mut [u8]) -> u32,
+    ) {
+        if vertex_buf_len == 0 {
+            return;
+        }
+
         let color = color.into();
         let image = image.into();
-        let text = text.as_ref();
         let image_info = graph.node_info(image);
         let transform = Mat4::from_translation(vec3(-1.0, -1.0, 0.0))
             * Mat4::from_scale(vec3(2.0 * scale, 2.0 * scale, 1.0))
@@ -160,7 +232,6 @@ impl BitmapFont {
                 0.0,
             ));
 
-        let vertex_buf_len = 120 * text.chars().count() as vk::DeviceSize;
         let mut vertex_buf = self
             .cache
             .lease(BufferInfo {
@@ -170,34 +241,10 @@ impl BitmapFont {
             })
             .unwrap();
 
-        let mut vertex_count = 0;
-
-        {
-            let vertex_buf =
-                &mut Buffer::mapped_slice_mut(&mut vertex_buf)[0..vertex_buf_len as usize];
-
-            let mut offset = 0;
-            for (data, char) in self.font.parse(text).map(|char| (char.tessellate(), char)) {
-                vertex_buf[offset..offset + 16].copy_from_slice(&data[0]);
-                vertex_buf[offset + 20..offset + 36].copy_from_slice(&data[1]);
-                vertex_buf[offset + 40..offset + 56].copy_from_slice(&data[2]);
-                vertex_buf[offset + 60..offset + 76].copy_from_slice(&data[3]);
-                vertex_buf[offset + 80..offset + 96].copy_from_slice(&data[4]);
-                vertex_buf[offset + 100..offset + 116].copy_from_slice(&data[5]);
-
-                let page_idx = char.page_index as i32;
-                let page_idx = page_idx.to_ne_bytes();
-                vertex_buf[offset + 16..offset + 20].copy_from_slice(&page_idx);
-                vertex_buf[offset + 36..offset + 40].copy_from_slice(&page_idx);
-                vertex_buf[offset + 56..offset + 60].copy_from_slice(&page_idx);
-                vertex_buf[offset + 76..offset + 80].copy_from_slice(&page_idx);
-                vertex_buf[offset + 96..offset + 100].copy_from_slice(&page_idx);
-                vertex_buf[offset + 116..offset + 120].copy_from_slice(&page_idx);
-
-                vertex_count += 6;
-                offset += 120;
-            }
-        }
+        let vertex_count = write_vertices(
+            self,
+            &mut Buffer::mapped_slice_mut(&mut vertex_buf)[0..vertex_buf_len as usize],
+        );
 
         let vertex_buf = graph.bind_node(vertex_buf);
 
@@ -232,6 +279,50 @@ impl BitmapFont {
                 .draw(vertex_count, 1, 0, 0);
         });
     }
+
+    /// Writes six vertices of 20 bytes each for every glyph of `text`, returning the vertex count.
+    fn tessellate(&self, text: &str, vertex_buf: &mut [u8]) -> u32 {
+        let mut vertex_count = 0;
+        let mut offset = 0;
+        for (data, char) in self.font.parse(text).map(|char| (char.tessellate(), char)) {
+            vertex_buf[offset..offset + 16].copy_from_slice(&data[0]);
+            vertex_buf[offset + 20..offset + 36].copy_from_slice(&data[1]);
+            vertex_buf[offset + 40..offset + 56].copy_from_slice(&data[2]);
+            vertex_buf[offset + 60..offset + 76].copy_from_slice(&data[3]);
+            vertex_buf[offset + 80..offset + 96].copy_from_slice(&data[4]);
+            vertex_buf[offset + 100..offset + 116].copy_from_slice(&data[5]);
+
+            let page_idx = char.page_index as i32;
+            let page_idx = page_idx.to_ne_bytes();
+            vertex_buf[offset + 16..offset + 20].copy_from_slice(&page_idx);
+            vertex_buf[offset + 36..offset + 40].copy_from_slice(&page_idx);
+            vertex_buf[offset + 56..offset + 60].copy_from_slice(&page_idx);
+            vertex_buf[offset + 76..offset + 80].copy_from_slice(&page_idx);
+            vertex_buf[offset + 96..offset + 100].copy_from_slice(&page_idx);
+            vertex_buf[offset + 116..offset + 120].copy_from_slice(&page_idx);
+
+            vertex_count += 6;
+            offset += 120;
+        }
+
+        vertex_count
+    }
+}
+
+/// Text which has been laid out by [`BitmapFont::layout`].
+///
+/// Re-use this for static strings such as labels in order to avoid laying out the same glyphs
+/// every frame.
+#[derive(Clone, Debug)]
+pub struct BitmapText {
+    vertices: Vec<u8>,
+}
+
+impl BitmapText {
+    /// Returns the number of vertices required to draw this text.
+    pub fn vertex_count(&self) -> u32 {
+        (self.vertices.len() / 20) as _
+    }
 }
 
 pub enum BitmapGlyphColor {
@@ -0,0 +1,112 @@
+use {super::spirv, screen_13::prelude::*, std::sync::Arc};
+
+/// Color grading using a three-dimensional lookup table (LUT).
+///
+/// Each color of the source image is replaced by the trilinearly filtered LUT texel found using
+/// its red, green and blue channels as coordinates; alpha is unchanged. LUTs are `Texture3D`
+/// images with the `SAMPLED` usage and any color format; `R8G8B8A8_UNORM` with 16 or 32 texels
+/// per axis is typical.
+pub struct ColorGrade {
+    cache: HashPool,
+    pipeline: Arc<ComputePipeline>,
+}
+
+impl ColorGrade {
+    pub fn new(device: &Arc<Device>) -> Result<Self, DriverError> {
+        let cache = HashPool::new(device);
+        let pipeline = Arc::new(ComputePipeline::create(device, spirv::COLOR_GRADE_COMP)?);
+
+        Ok(Self { cache, pipeline })
+    }
+
+    /// Records the upload of an identity LUT with `size` texels per axis, returning the result.
+    ///
+    /// Grading with this LUT passes colors through unchanged, so it is a useful starting point
+    /// for LUTs which are edited using image tools.
+    ///
+    /// Returns `DriverError::InvalidData` if `size` is less than two.
+    pub fn record_identity_lut(
+        &mut self,
+        render_graph: &mut RenderGraph,
+        size: u32,
+    ) -> Result<ImageLeaseNode, DriverError> {
+        if size < 2 {
+            warn!("LUT must have at least two texels per axis");
+
+            return Err(DriverError::InvalidData);
+        }
+
+        let max = (size - 1) as f32;
+        let unorm = |val: u32| (val as f32 / max * u8::MAX as f32).round() as u8;
+        let mut pixels = Vec::with_capacity(4 * (size * size * size) as usize);
+        for blue in 0..size {
+            for green in 0..size {
+                for red in 0..size {
+                    pixels.extend_from_slice(&[unorm(red), unorm(green), unorm(blue), u8::MAX]);
+                }
+            }
+        }
+
+        let mut pixel_buf = self.cache.lease(BufferInfo::new_mappable(
+            pixels.len() as _,
+            vk::BufferUsageFlags::TRANSFER_SRC,
+        ))?;
+        Buffer::mapped_slice_mut(&mut pixel_buf)[0..pixels.len()].copy_from_slice(&pixels);
+
+        let pixel_buf = render_graph.bind_node(pixel_buf);
+        let lut = render_graph.bind_node(self.cache.lease(ImageInfo::new_3d(
+            vk::Format::R8G8B8A8_UNORM,
+            size,
+            size,
+            size,
+            vk::ImageUsageFlags::SAMPLED | vk::ImageUsageFlags::TRANSFER_DST,
+        ))?);
+        render_graph.copy_buffer_to_image(pixel_buf, lut);
+
+        Ok(lut)
+    }
+
+    /// Records a pass which grades `image` using `lut`, returning the result.
+    pub fn record(
+        &mut self,
+        render_graph: &mut RenderGraph,
+        image: impl Into<AnyImageNode>,
+        lut: impl Into<AnyImageNode>,
+    ) -> ImageLeaseNode {
+        let image = image.into();
+        let lut = lut.into();
+        let image_info = render_graph.node_info(image);
+
+        debug_assert_eq!(
+            render_graph.node_info(lut).ty,
+            ImageType::Texture3D,
+            "LUT must be a 3D image"
+        );
+
+        let dst_image = render_graph.bind_node(
+            self.cache
+                .lease(ImageInfo::new_2d(
+                    vk::Format::R8G8B8A8_UNORM,
+                    image_info.width,
+                    image_info.height,
+                    vk::ImageUsageFlags::SAMPLED
+                        | vk::ImageUsageFlags::STORAGE
+                        | vk::ImageUsageFlags::TRANSFER_DST
+                        | vk::ImageUsageFlags::TRANSFER_SRC,
+                ))
+                .unwrap(),
+        );
+
+        render_graph
+            .begin_pass("color grade")
+            .bind_pipeline(&self.pipeline)
+            .read_descriptor(0, image)
+            .read_descriptor(1, lut)
+            .write_descriptor(2, dst_image)
+            .record_compute(move |compute| {
+                compute.dispatch(image_info.width, image_info.height, 1);
+            });
+
+        dst_image
+    }
+}
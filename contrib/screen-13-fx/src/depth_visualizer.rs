@@ -0,0 +1,85 @@
+use {super::spirv, bytemuck::cast_slice, screen_13::prelude::*, std::sync::Arc};
+
+/// Converts a perspective depth image into a viewable grayscale image.
+///
+/// Depth values are linearized using the near and far planes of the projection and then
+/// normalized so that near surfaces are white and far surfaces are black. The result may be drawn
+/// as an imgui image or read back for screenshots.
+///
+/// The depth image must use a depth-only format such as `D32_SFLOAT` and include the `SAMPLED`
+/// usage.
+pub struct DepthVisualizer {
+    cache: HashPool,
+    pipeline: Arc<ComputePipeline>,
+
+    /// Distance to the far plane of the projection. Defaults to `1000.0`.
+    pub far: f32,
+
+    /// Distance to the near plane of the projection. Defaults to `0.1`.
+    pub near: f32,
+
+    /// Set when the projection stores near surfaces at depth one and far surfaces at depth zero.
+    /// Defaults to `false`.
+    pub reverse_z: bool,
+}
+
+impl DepthVisualizer {
+    pub fn new(device: &Arc<Device>) -> Result<Self, DriverError> {
+        let cache = HashPool::new(device);
+        let pipeline = Arc::new(ComputePipeline::create(
+            device,
+            spirv::DEPTH_VISUALIZER_COMP,
+        )?);
+
+        Ok(Self {
+            cache,
+            pipeline,
+            far: 1000.0,
+            near: 0.1,
+            reverse_z: false,
+        })
+    }
+
+    /// Records a pass which visualizes `depth_image`, returning the result.
+    pub fn record(
+        &mut self,
+        render_graph: &mut RenderGraph,
+        depth_image: impl Into<AnyImageNode>,
+    ) -> ImageLeaseNode {
+        let depth_image = depth_image.into();
+        let depth_image_info = render_graph.node_info(depth_image);
+        let dst_image = render_graph.bind_node(
+            self.cache
+                .lease(ImageInfo::new_2d(
+                    vk::Format::R8G8B8A8_UNORM,
+                    depth_image_info.width,
+                    depth_image_info.height,
+                    vk::ImageUsageFlags::SAMPLED
+                        | vk::ImageUsageFlags::STORAGE
+                        | vk::ImageUsageFlags::TRANSFER_DST
+                        | vk::ImageUsageFlags::TRANSFER_SRC,
+                ))
+                .unwrap(),
+        );
+        let push_consts = if self.reverse_z {
+            [self.far, self.near]
+        } else {
+            [self.near, self.far]
+        };
+
+        render_graph
+            .begin_pass("depth visualizer")
+            .bind_pipeline(&self.pipeline)
+            .read_descriptor(0, depth_image)
+            .write_descriptor(1, dst_image)
+            .record_compute(move |compute| {
+                compute.push_constants(cast_slice(&push_consts)).dispatch(
+                    depth_image_info.width,
+                    depth_image_info.height,
+                    1,
+                );
+            });
+
+        dst_image
+    }
+}
@@ -0,0 +1,55 @@
+use {super::spirv, screen_13::prelude::*, std::sync::Arc};
+
+/// Reduces an image to half of its size by averaging each 2x2 block of texels.
+///
+/// This is a building block for bloom, blur and mip chain generation. Odd source dimensions are
+/// rounded up, with the final row or column of texels sampled twice. Results are stored as
+/// `R16G16B16A16_SFLOAT` so that high dynamic range input keeps its range.
+pub struct Downsample {
+    cache: HashPool,
+    pipeline: Arc<ComputePipeline>,
+}
+
+impl Downsample {
+    pub fn new(device: &Arc<Device>) -> Result<Self, DriverError> {
+        let cache = HashPool::new(device);
+        let pipeline = Arc::new(ComputePipeline::create(device, spirv::DOWNSAMPLE_2X_COMP)?);
+
+        Ok(Self { cache, pipeline })
+    }
+
+    /// Records a pass which downsamples `image`, returning the half-size result.
+    pub fn record(
+        &mut self,
+        render_graph: &mut RenderGraph,
+        image: impl Into<AnyImageNode>,
+    ) -> ImageLeaseNode {
+        let image = image.into();
+        let image_info = render_graph.node_info(image);
+        let width = (image_info.width + 1) >> 1;
+        let height = (image_info.height + 1) >> 1;
+        let dst_image = render_graph.bind_node(
+            self.cache
+                .lease(ImageInfo::new_2d(
+                    vk::Format::R16G16B16A16_SFLOAT,
+                    width,
+                    height,
+                    vk::ImageUsageFlags::SAMPLED
+                        | vk::ImageUsageFlags::STORAGE
+                        | vk::ImageUsageFlags::TRANSFER_SRC,
+                ))
+                .unwrap(),
+        );
+
+        render_graph
+            .begin_pass("downsample 2x")
+            .bind_pipeline(&self.pipeline)
+            .read_descriptor(0, image)
+            .write_descriptor(1, dst_image)
+            .record_compute(move |compute| {
+                compute.dispatch(width, height, 1);
+            });
+
+        dst_image
+    }
+}
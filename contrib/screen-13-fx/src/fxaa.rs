@@ -0,0 +1,101 @@
+use {super::spirv, bytemuck::cast_slice, screen_13::prelude::*, std::sync::Arc};
+
+/// Fast approximate anti-aliasing (FXAA) post-process.
+///
+/// This smooths high-contrast edges of a rendered image using only its color data, making it a
+/// cheaper alternative to multisampling. Regions without edges are copied unchanged.
+///
+/// FXAA runs in a fragment shader and so the result may be written to any color attachment,
+/// including swapchain images which do not support storage.
+pub struct Fxaa {
+    cache: HashPool,
+    pipeline: Arc<GraphicPipeline>,
+
+    /// Minimum local contrast, relative to the brightest neighbor, required to process a pixel.
+    ///
+    /// Lower values smooth more edges at some performance cost. Defaults to `0.125`.
+    pub edge_threshold: f32,
+
+    /// Minimum absolute contrast required to process a pixel, which avoids processing dark
+    /// regions. Defaults to `0.0312`.
+    pub edge_threshold_min: f32,
+
+    /// Amount of sub-pixel aliasing removal, from `0.0` (off) to `1.0` (softest). Defaults to
+    /// `0.75`.
+    pub subpixel_quality: f32,
+}
+
+impl Fxaa {
+    pub fn new(device: &Arc<Device>) -> Result<Self, DriverError> {
+        let cache = HashPool::new(device);
+        let pipeline = Arc::new(GraphicPipeline::create(
+            device,
+            GraphicPipelineInfo::new(),
+            [
+                Shader::new_vertex(spirv::FXAA_VERT),
+                Shader::new_fragment(spirv::FXAA_FRAG),
+            ],
+        )?);
+
+        Ok(Self {
+            cache,
+            pipeline,
+            edge_threshold: 0.125,
+            edge_threshold_min: 0.0312,
+            subpixel_quality: 0.75,
+        })
+    }
+
+    /// Records a pass which anti-aliases `image`, returning the result.
+    pub fn record(
+        &mut self,
+        render_graph: &mut RenderGraph,
+        image: impl Into<AnyImageNode>,
+    ) -> ImageLeaseNode {
+        let image = image.into();
+        let image_info = render_graph.node_info(image);
+        let dst_image = render_graph.bind_node(
+            self.cache
+                .lease(ImageInfo::new_2d(
+                    vk::Format::R8G8B8A8_UNORM,
+                    image_info.width,
+                    image_info.height,
+                    vk::ImageUsageFlags::COLOR_ATTACHMENT
+                        | vk::ImageUsageFlags::SAMPLED
+                        | vk::ImageUsageFlags::TRANSFER_DST
+                        | vk::ImageUsageFlags::TRANSFER_SRC,
+                ))
+                .unwrap(),
+        );
+
+        self.record_into(render_graph, image, dst_image);
+
+        dst_image
+    }
+
+    /// Records a pass which anti-aliases `image` into `dst`, which may be the swapchain or any
+    /// other color-attachment image. The image is stretched to cover `dst`.
+    pub fn record_into(
+        &self,
+        render_graph: &mut RenderGraph,
+        image: impl Into<AnyImageNode>,
+        dst: impl Into<AnyImageNode>,
+    ) {
+        let push_consts = [
+            self.edge_threshold,
+            self.edge_threshold_min,
+            self.subpixel_quality,
+        ];
+
+        render_graph
+            .begin_pass("fxaa")
+            .bind_pipeline(&self.pipeline)
+            .read_descriptor(0, image.into())
+            .store_color(0, dst.into())
+            .record_subpass(move |subpass| {
+                // Draw a quad with implicit vertices (no buffer)
+                subpass.push_constants(cast_slice(&push_consts));
+                subpass.draw(6, 1, 0, 0);
+            });
+    }
+}
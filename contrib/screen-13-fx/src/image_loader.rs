@@ -1,10 +1,14 @@
 use {
-    super::BitmapFont, anyhow::Context, bmfont::BMFont, inline_spirv::include_spirv,
-    screen_13::prelude::*, std::sync::Arc,
+    super::{spirv, BitmapFont},
+    anyhow::Context,
+    bmfont::BMFont,
+    screen_13::prelude::*,
+    std::sync::Arc,
 };
 
+// Note: `atom` need not be a power of two
 fn align_up_u32(val: u32, atom: u32) -> u32 {
-    (val + atom - 1) & !(atom - 1)
+    (val + atom - 1) / atom * atom
 }
 
 /// Describes the channels and pixel stride of an image format
@@ -41,11 +45,11 @@ impl ImageLoader {
             cache: HashPool::new(device),
             _decode_r_rg: Arc::new(ComputePipeline::create(
                 device,
-                include_spirv!("res/shader/compute/decode_bitmap_r_rg.comp", comp).as_slice(),
+                spirv::DECODE_BITMAP_R_RG_COMP,
             )?),
             decode_rgb_rgba: Arc::new(ComputePipeline::create(
                 device,
-                include_spirv!("res/shader/compute/decode_bitmap_rgb_rgba.comp", comp).as_slice(),
+                spirv::DECODE_BITMAP_RGB_RGBA_COMP,
             )?),
             device: Arc::clone(device),
         })
@@ -129,7 +133,7 @@ impl ImageLoader {
         );
 
         #[cfg(debug_assertions)]
-        if pixels.len() >= format.stride() * (width * height) as usize {
+        if pixels.len() > format.stride() * (width * height) as usize {
             warn!("unused data");
         }
 
@@ -184,15 +188,20 @@ impl ImageLoader {
                 let pixel_buf = render_graph.bind_node(pixel_buf);
 
                 // We create a temporary storage image because SRGB support isn't wide enough to
-                // have SRGB storage images directly
-                let temp_image =
-                    render_graph.bind_node(self.create_image(format, width, height, false, true)?);
+                // have SRGB storage images directly; each decode invocation writes four pixels so
+                // the width is padded to keep the final invocation of each row in bounds
+                let dispatch_x = align_up_u32(width, 4) >> 2;
+                let dispatch_y = height;
+                let temp_image = render_graph.bind_node(self.create_image(
+                    format,
+                    dispatch_x << 2,
+                    height,
+                    false,
+                    true,
+                )?);
 
                 // Copy host-local data in the buffer to the temporary buffer on the GPU and then
                 // use a compute shader to decode it before copying it over the output image
-
-                let dispatch_x = (width >> 2) - 1 + (width % 3); // HACK: -1 FOR NOW but do fix
-                let dispatch_y = height;
                 render_graph
                     .begin_pass("Decode RGB image")
                     .bind_pipeline(&self.decode_rgb_rgba)
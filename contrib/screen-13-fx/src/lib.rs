@@ -1,18 +1,31 @@
 pub mod prelude {
     pub use super::{
-        BitmapFont, BitmapGlyphColor, ComputePresenter, GraphicPresenter, ImageFormat, ImageLoader,
-        Transition, TransitionPipeline,
+        BitmapFont, BitmapGlyphColor, BitmapText, ColorGrade, ComputePresenter, DepthVisualizer,
+        Downsample, Fxaa, GraphicPresenter, ImageFormat, ImageLoader, SummedAreaTable, Transition,
+        TransitionPipeline,
     };
 }
 
 mod bitmap_font;
+mod color_grade;
+mod depth_visualizer;
+mod downsample;
+mod fxaa;
 mod image_loader;
 mod presenter;
+mod summed_area_table;
 mod transition;
 
+pub mod spirv;
+
 pub use self::{
-    bitmap_font::{BitmapFont, BitmapGlyphColor},
+    bitmap_font::{BitmapFont, BitmapGlyphColor, BitmapText},
+    color_grade::ColorGrade,
+    depth_visualizer::DepthVisualizer,
+    downsample::Downsample,
+    fxaa::Fxaa,
     image_loader::{ImageFormat, ImageLoader},
     presenter::{ComputePresenter, GraphicPresenter},
+    summed_area_table::SummedAreaTable,
     transition::{Transition, TransitionPipeline},
 };
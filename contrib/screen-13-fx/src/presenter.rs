@@ -1,7 +1,7 @@
 use {
+    super::spirv,
     bytemuck::cast_slice,
     glam::{vec3, Mat4},
-    inline_spirv::include_spirv,
     screen_13::prelude::*,
     std::sync::Arc,
 };
@@ -10,14 +10,8 @@ pub struct ComputePresenter([Arc<ComputePipeline>; 2]);
 
 impl ComputePresenter {
     pub fn new(device: &Arc<Device>) -> Result<Self, DriverError> {
-        let pipeline1 = Arc::new(ComputePipeline::create(
-            device,
-            include_spirv!("res/shader/compute/present1.comp", comp).as_slice(),
-        )?);
-        let pipeline2 = Arc::new(ComputePipeline::create(
-            device,
-            include_spirv!("res/shader/compute/present2.comp", comp).as_slice(),
-        )?);
+        let pipeline1 = Arc::new(ComputePipeline::create(device, spirv::PRESENT1_COMP)?);
+        let pipeline2 = Arc::new(ComputePipeline::create(device, spirv::PRESENT2_COMP)?);
 
         Ok(Self([pipeline1, pipeline2]))
     }
@@ -77,52 +71,61 @@ pub struct GraphicPresenter {
 
 impl GraphicPresenter {
     pub fn new(device: &Arc<Device>) -> Result<Self, DriverError> {
+        Self::with_blend(device, BlendMode::REPLACE)
+    }
+
+    /// Creates a presenter which draws images using the given blend mode, which is useful for
+    /// compositing an image over the existing contents of the destination.
+    pub fn with_blend(device: &Arc<Device>, blend: BlendMode) -> Result<Self, DriverError> {
         Ok(Self {
             pipeline: Arc::new(GraphicPipeline::create(
                 device,
-                GraphicPipelineInfo::new(),
+                GraphicPipelineInfo::new().blend(blend),
                 [
-                    Shader::new_vertex(
-                        include_spirv!("res/shader/graphic/present.vert", vert).as_slice(),
-                    ),
-                    Shader::new_fragment(
-                        include_spirv!("res/shader/graphic/present.frag", frag).as_slice(),
-                    ),
+                    Shader::new_vertex(spirv::PRESENT_VERT),
+                    Shader::new_fragment(spirv::PRESENT_FRAG),
                 ],
             )?),
         })
     }
 
+    /// Draws a full-screen quad sampling `image` into `dst`, which may be the swapchain or any
+    /// other color-attachment image. The image is scaled to cover `dst`.
     pub fn present_image(
         &self,
         graph: &mut RenderGraph,
         image: impl Into<AnyImageNode>,
-        swapchain: SwapchainImageNode,
+        dst: impl Into<AnyImageNode>,
     ) {
         let image = image.into();
+        let dst = dst.into();
         let image_info = graph.node_info(image);
-        let swapchain_info = graph.node_info(swapchain);
+        let dst_info = graph.node_info(dst);
 
         let (image_width, image_height) = (image_info.width as f32, image_info.height as f32);
-        let (swapchain_width, swapchain_height) =
-            (swapchain_info.width as f32, swapchain_info.height as f32);
+        let (dst_width, dst_height) = (dst_info.width as f32, dst_info.height as f32);
 
-        let scale = (swapchain_width / image_width).max(swapchain_height / image_height);
+        let scale = (dst_width / image_width).max(dst_height / image_height);
         let transform = Mat4::from_scale(vec3(
-            scale * image_width / swapchain_width,
-            scale * image_height / swapchain_height,
+            scale * image_width / dst_width,
+            scale * image_height / dst_height,
             1.0,
         ));
 
-        graph
+        let mut pass = graph
             .begin_pass("present (from graphic)")
             .bind_pipeline(&self.pipeline)
-            .read_descriptor(0, image)
-            .store_color(0, swapchain)
-            .record_subpass(move |subpass| {
-                // Draw a quad with implicit vertices (no buffer)
-                subpass.push_constants(cast_slice(&transform.to_cols_array()));
-                subpass.draw(6, 1, 0, 0);
-            });
+            .read_descriptor(0, image);
+
+        // Blended presentation must preserve whatever was already in the destination
+        if self.pipeline.info.blend != BlendMode::REPLACE {
+            pass = pass.load_color(0, dst);
+        }
+
+        pass.store_color(0, dst).record_subpass(move |subpass| {
+            // Draw a quad with implicit vertices (no buffer)
+            subpass.push_constants(cast_slice(&transform.to_cols_array()));
+            subpass.draw(6, 1, 0, 0);
+        });
     }
 }
@@ -0,0 +1,111 @@
+//! Compiled SPIR-V of the shaders used by this crate.
+//!
+//! These may be loaded into tools such as RenderDoc, or used to build new pipelines which follow
+//! the same descriptor and push constant layouts. Every shader uses the `main` entry point and
+//! descriptor set `0`.
+
+use inline_spirv::include_spirv;
+
+/// Grades colors using a 3D lookup table; each invocation writes one destination texel.
+///
+/// - Binding `0`: sampled source image
+/// - Binding `1`: sampled 3D lookup table image
+/// - Binding `2`: `rgba8` storage destination image
+pub const COLOR_GRADE_COMP: &[u32] = include_spirv!("res/shader/compute/color_grade.comp", comp);
+
+/// Decodes tightly-packed `R8G8` bitmap data (unfinished).
+///
+/// - Push constant `0`: `uint` row stride of the pixel buffer, in `uint`s
+/// - Binding `0`: read-only storage buffer of pixel data
+/// - Binding `1`: `rgba8ui` storage image
+pub const DECODE_BITMAP_R_RG_COMP: &[u32] =
+    include_spirv!("res/shader/compute/decode_bitmap_r_rg.comp", comp);
+
+/// Decodes tightly-packed `R8G8B8` bitmap data into four-channel texels; each invocation writes
+/// four texels of one row.
+///
+/// - Push constant `0`: `uint` row stride of the pixel buffer, in `uint`s
+/// - Binding `0`: read-only storage buffer of pixel data
+/// - Binding `1`: `rgba8ui` storage image
+pub const DECODE_BITMAP_RGB_RGBA_COMP: &[u32] =
+    include_spirv!("res/shader/compute/decode_bitmap_rgb_rgba.comp", comp);
+
+/// Converts perspective depth into normalized grayscale; each invocation writes one destination
+/// texel.
+///
+/// - Push constants `0` and `4`: `float` view distances of depth zero and depth one
+/// - Binding `0`: sampled depth image
+/// - Binding `1`: `rgba8` storage destination image
+pub const DEPTH_VISUALIZER_COMP: &[u32] =
+    include_spirv!("res/shader/compute/depth_visualizer.comp", comp);
+
+/// Averages 2x2 blocks of the source image; each invocation writes one destination texel.
+///
+/// - Binding `0`: sampled source image
+/// - Binding `1`: `rgba16f` storage destination image
+pub const DOWNSAMPLE_2X_COMP: &[u32] =
+    include_spirv!("res/shader/compute/downsample_2x.comp", comp);
+
+/// Glyph fragment shader which mixes the glyph and outline colors using the red and green
+/// channels of the page.
+///
+/// - Specialization constant `0`: `uint` number of pages
+/// - Push constants `80` and `96`: `vec4` glyph and outline colors
+/// - Binding `0`: array of sampled page images
+pub const FONT_FRAG: &[u32] = include_spirv!("res/shader/graphic/font.frag", frag);
+
+/// Glyph vertex shader.
+///
+/// - Specialization constant `0`: `uint` number of pages
+/// - Push constants `0` and `64`: `mat4` view-projection and `vec2` inverse framebuffer extent
+/// - Binding `0`: array of sampled page images
+/// - Vertex inputs `0`, `1` and `2`: `vec2` position, `vec2` texel coordinate and `int` page
+pub const FONT_VERT: &[u32] = include_spirv!("res/shader/graphic/font.vert", vert);
+
+/// Fast approximate anti-aliasing fragment shader which writes color attachment `0`.
+///
+/// - Push constants `0`, `4` and `8`: `float` edge threshold, minimum edge threshold and
+///   sub-pixel quality
+/// - Binding `0`: sampled source image
+pub const FXAA_FRAG: &[u32] = include_spirv!("res/shader/graphic/fxaa.frag", frag);
+
+/// Draws a full quad using six vertices and no vertex buffer, for use with [`FXAA_FRAG`].
+pub const FXAA_VERT: &[u32] = include_spirv!("res/shader/graphic/fxaa.vert", vert);
+
+/// Fills the destination with one source image using a Catmull-Rom filter; each invocation
+/// writes one destination texel.
+///
+/// - Binding `0`: sampled source image
+/// - Binding `1`: `rgba8` storage destination image
+pub const PRESENT1_COMP: &[u32] = include_spirv!("res/shader/compute/present1.comp", comp);
+
+/// Fills the destination with the first source image blended over the second; each invocation
+/// writes one destination texel.
+///
+/// - Binding `0`: array of two sampled source images
+/// - Binding `1`: `rgba8` storage destination image
+pub const PRESENT2_COMP: &[u32] = include_spirv!("res/shader/compute/present2.comp", comp);
+
+/// Samples the source image, including alpha.
+///
+/// - Binding `0`: sampled source image
+pub const PRESENT_FRAG: &[u32] = include_spirv!("res/shader/graphic/present.frag", frag);
+
+/// Draws a full quad using six vertices and no vertex buffer.
+///
+/// - Push constant `0`: `mat4` vertex transform
+pub const PRESENT_VERT: &[u32] = include_spirv!("res/shader/graphic/present.vert", vert);
+
+/// Sums each column of the source image; each invocation processes one column.
+///
+/// - Binding `0`: sampled source image
+/// - Binding `1`: `rgba32f` storage destination image
+pub const SUMMED_AREA_TABLE_COLUMNS_COMP: &[u32] =
+    include_spirv!("res/shader/compute/summed_area_table_columns.comp", comp);
+
+/// Sums each row of the source image; each invocation processes one row.
+///
+/// - Binding `0`: sampled source image
+/// - Binding `1`: `rgba32f` storage destination image
+pub const SUMMED_AREA_TABLE_ROWS_COMP: &[u32] =
+    include_spirv!("res/shader/compute/summed_area_table_rows.comp", comp);
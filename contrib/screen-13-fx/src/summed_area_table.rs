@@ -0,0 +1,85 @@
+use {super::spirv, screen_13::prelude::*, std::sync::Arc};
+
+/// Computes the summed-area table (integral image) of an image.
+///
+/// Each texel of the result holds the sum of all source texels above and to the left of it,
+/// inclusive, so the sum of any rectangle may be found using four reads. This is useful for
+/// variable-radius box filters and blurs.
+///
+/// Results are stored as `R32G32B32A32_SFLOAT` and are accumulated using compensated summation;
+/// large images of normalized values remain accurate to roughly seven significant digits.
+///
+/// _NOTE:_ This is not a parallel prefix scan. The table is built in two passes where a single
+/// invocation serially sums each row and then each column, so each invocation performs O(width)
+/// or O(height) work and only `height` or `width` invocations run in parallel. This is suitable for
+/// images up to a few thousand texels per side; very large images will take proportionally
+/// longer.
+pub struct SummedAreaTable {
+    cache: HashPool,
+    columns: Arc<ComputePipeline>,
+    rows: Arc<ComputePipeline>,
+}
+
+impl SummedAreaTable {
+    pub fn new(device: &Arc<Device>) -> Result<Self, DriverError> {
+        let cache = HashPool::new(device);
+        let columns = Arc::new(ComputePipeline::create(
+            device,
+            spirv::SUMMED_AREA_TABLE_COLUMNS_COMP,
+        )?);
+        let rows = Arc::new(ComputePipeline::create(
+            device,
+            spirv::SUMMED_AREA_TABLE_ROWS_COMP,
+        )?);
+
+        Ok(Self {
+            cache,
+            columns,
+            rows,
+        })
+    }
+
+    /// Records the passes which compute the summed-area table of `image`, returning the result.
+    ///
+    /// The sum of the rectangle with corners `(x0, y0)` and `(x1, y1)` (exclusive of `x0` and
+    /// `y0`) is `sat[x1, y1] - sat[x0, y1] - sat[x1, y0] + sat[x0, y0]`.
+    pub fn record(
+        &mut self,
+        render_graph: &mut RenderGraph,
+        image: impl Into<AnyImageNode>,
+    ) -> ImageLeaseNode {
+        let image = image.into();
+        let image_info = render_graph.node_info(image);
+        let sat_info = ImageInfo::new_2d(
+            vk::Format::R32G32B32A32_SFLOAT,
+            image_info.width,
+            image_info.height,
+            vk::ImageUsageFlags::SAMPLED
+                | vk::ImageUsageFlags::STORAGE
+                | vk::ImageUsageFlags::TRANSFER_SRC,
+        )
+        .build();
+        let row_image = render_graph.bind_node(self.cache.lease(sat_info).unwrap());
+        let sat_image = render_graph.bind_node(self.cache.lease(sat_info).unwrap());
+
+        render_graph
+            .begin_pass("summed area table rows")
+            .bind_pipeline(&self.rows)
+            .read_descriptor(0, image)
+            .write_descriptor(1, row_image)
+            .record_compute(move |compute| {
+                compute.dispatch(image_info.height, 1, 1);
+            });
+
+        render_graph
+            .begin_pass("summed area table columns")
+            .bind_pipeline(&self.columns)
+            .read_descriptor(0, row_image)
+            .write_descriptor(1, sat_image)
+            .record_compute(move |compute| {
+                compute.dispatch(image_info.width, 1, 1);
+            });
+
+        sat_image
+    }
+}
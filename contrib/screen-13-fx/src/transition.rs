@@ -775,7 +775,7 @@ impl TransitionPipeline {
                 push_consts.extend_from_slice(&background_color[0].to_ne_bytes());
                 push_consts.extend_from_slice(&background_color[1].to_ne_bytes());
                 push_consts.extend_from_slice(&background_color[2].to_ne_bytes());
-                push_consts.extend_from_slice(&[0u8; 4]); // padding
+                push_consts.extend_from_slice(&[0u8; 4]); // padding: the shader declares a vec4
             }
             &Transition::CircleCrop { background_color } => {
                 push_consts.extend_from_slice(&[0u8; 12]); // padding
@@ -783,7 +783,6 @@ impl TransitionPipeline {
                 push_consts.extend_from_slice(&background_color[1].to_ne_bytes());
                 push_consts.extend_from_slice(&background_color[2].to_ne_bytes());
                 push_consts.extend_from_slice(&background_color[3].to_ne_bytes());
-                push_consts.extend_from_slice(&[0u8; 4]); // padding
             }
             &Transition::CircleOpen {
                 smoothness,
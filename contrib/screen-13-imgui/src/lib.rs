@@ -1,53 +1,124 @@
 pub mod prelude {
-    pub use super::{imgui, Condition, ImGui, Ui};
+    pub use super::{imgui, Condition, HiDpiMode, ImGui, ImGuiError, Ui};
 }
 
-pub use imgui::{self, Condition, Ui};
+pub use {
+    imgui::{self, Condition, Ui},
+    imgui_winit_support::HiDpiMode,
+};
 
 use {
     bytemuck::cast_slice,
     imgui::{Context, DrawCmd, DrawCmdParams},
-    imgui_winit_support::{HiDpiMode, WinitPlatform},
+    imgui_winit_support::WinitPlatform,
     inline_spirv::include_spirv,
     screen_13::prelude::*,
-    std::{sync::Arc, time::Duration},
+    std::{error::Error, fmt::Formatter, sync::Arc, time::Duration},
+    winit::error::ExternalError,
 };
 
+/// Clamps an imgui clip rectangle, in framebuffer pixels, to the framebuffer.
+///
+/// Returns `None` for fully-clipped elements, which should be skipped because some drivers reject
+/// zero-area or negative scissor extents.
+fn clamp_scissor(clip_rect: [f32; 4], width: f32, height: f32) -> Option<(i32, i32, u32, u32)> {
+    let x = clip_rect[0].floor().max(0.0);
+    let y = clip_rect[1].floor().max(0.0);
+    let right = clip_rect[2].ceil().min(width);
+    let bottom = clip_rect[3].ceil().min(height);
+
+    if right <= x || bottom <= y {
+        return None;
+    }
+
+    Some((x as _, y as _, (right - x) as _, (bottom - y) as _))
+}
+
 #[derive(Debug)]
 pub struct ImGui {
     context: Context,
     font_atlas_image: Option<Arc<Lease<Image>>>,
+    hidpi_mode: HiDpiMode,
     pipeline: Arc<GraphicPipeline>,
     platform: WinitPlatform,
     pool: HashPool,
 }
 
 impl ImGui {
-    pub fn new(device: &Arc<Device>) -> Self {
+    pub fn new(device: &Arc<Device>) -> Result<Self, DriverError> {
+        Self::with_blend(
+            device,
+            // Straight alpha source blended into a transparent image produces a
+            // pre-multiplied alpha result
+            BlendMode::new()
+                .blend_enable(true)
+                .src_color_blend_factor(vk::BlendFactor::SRC_ALPHA)
+                .dst_color_blend_factor(vk::BlendFactor::ONE_MINUS_SRC_ALPHA)
+                .src_alpha_blend_factor(vk::BlendFactor::ONE)
+                .dst_alpha_blend_factor(vk::BlendFactor::ONE_MINUS_SRC_ALPHA)
+                .build(),
+        )
+    }
+
+    /// Creates an imgui renderer which blends draw lists into the output image using the given
+    /// blend mode.
+    ///
+    /// The default blend mode, used by [`ImGui::new`], is straight alpha which produces a
+    /// pre-multiplied alpha image.
+    pub fn with_blend(device: &Arc<Device>, blend: BlendMode) -> Result<Self, DriverError> {
         let mut context = Context::create();
         let platform = WinitPlatform::init(&mut context);
         let pool = HashPool::new(device);
-        let pipeline = Arc::new(
-            GraphicPipeline::create(
-                device,
-                GraphicPipelineInfo::new()
-                    .blend(BlendMode::PRE_MULTIPLIED_ALPHA)
-                    .cull_mode(vk::CullModeFlags::NONE),
-                [
-                    Shader::new_vertex(include_spirv!("res/shader/imgui.vert", vert).as_slice()),
-                    Shader::new_fragment(include_spirv!("res/shader/imgui.frag", frag).as_slice()),
-                ],
-            )
-            .unwrap(),
-        );
-
-        Self {
+        let pipeline = Arc::new(GraphicPipeline::create(
+            device,
+            GraphicPipelineInfo::new()
+                .blend(blend)
+                .cull_mode(vk::CullModeFlags::NONE),
+            [
+                Shader::new_vertex(include_spirv!("res/shader/imgui.vert", vert).as_slice()),
+                Shader::new_fragment(include_spirv!("res/shader/imgui.frag", frag).as_slice()),
+            ],
+        )?);
+
+        Ok(Self {
             context,
             font_atlas_image: None,
+            hidpi_mode: HiDpiMode::Default,
             pipeline,
             platform,
             pool,
-        }
+        })
+    }
+
+    /// Returns the blend mode used to draw into the output image.
+    pub fn blend(&self) -> BlendMode {
+        self.pipeline.info.blend
+    }
+
+    /// Returns the DPI scaling mode used when attaching to the window.
+    pub fn hidpi_mode(&self) -> HiDpiMode {
+        self.hidpi_mode
+    }
+
+    /// Sets the DPI scaling mode used when attaching to the window.
+    ///
+    /// The font atlas is rebuilt during the next draw if the effective scale factor changes.
+    pub fn set_hidpi_mode(&mut self, hidpi_mode: HiDpiMode) {
+        self.hidpi_mode = hidpi_mode;
+    }
+
+    /// Returns `true` if imgui used the mouse input during the last draw.
+    ///
+    /// Applications should not also handle mouse input while this is set.
+    pub fn want_capture_mouse(&self) -> bool {
+        self.context.io().want_capture_mouse
+    }
+
+    /// Returns `true` if imgui used the keyboard input during the last draw.
+    ///
+    /// Applications should not also handle keyboard input while this is set.
+    pub fn want_capture_keyboard(&self) -> bool {
+        self.context.io().want_capture_keyboard
     }
 
     // TODO: This produces an image which is RGBA8 UNORM and has STORAGE set. *We* don't need storage here and should instead ask the user what settings to give the output image.....
@@ -58,14 +129,14 @@ impl ImGui {
         window: &Window,
         render_graph: &mut RenderGraph,
         ui_func: impl FnOnce(&mut Ui),
-    ) -> ImageLeaseNode {
+    ) -> Result<ImageLeaseNode, ImGuiError> {
         let hidpi = self.platform.hidpi_factor();
 
         self.platform
-            .attach_window(self.context.io_mut(), window, HiDpiMode::Default);
+            .attach_window(self.context.io_mut(), window, self.hidpi_mode);
 
         if self.font_atlas_image.is_none() || self.platform.hidpi_factor() != hidpi {
-            self.lease_font_atlas_image(render_graph);
+            self.lease_font_atlas_image(render_graph)?;
         }
 
         let io = self.context.io_mut();
@@ -75,9 +146,7 @@ impl ImGui {
             self.platform.handle_event(io, window, event);
         }
 
-        self.platform
-            .prepare_frame(io, window)
-            .expect("Unable to prepare ImGui frame");
+        self.platform.prepare_frame(io, window)?;
 
         // Let the caller draw the GUI
         let mut ui = self.context.frame();
@@ -87,33 +156,26 @@ impl ImGui {
         self.platform.prepare_render(&ui, window);
         let draw_data = ui.render();
 
-        let image = render_graph.bind_node(
-            self.pool
-                .lease(ImageInfo::new_2d(
-                    vk::Format::R8G8B8A8_UNORM,
-                    window.inner_size().width,
-                    window.inner_size().height,
-                    vk::ImageUsageFlags::COLOR_ATTACHMENT
-                        | vk::ImageUsageFlags::SAMPLED
-                        | vk::ImageUsageFlags::STORAGE
-                        | vk::ImageUsageFlags::TRANSFER_SRC, // TODO: Make TRANSFER_SRC an "extra flags"
-                ))
-                .unwrap(),
-        );
+        let image = render_graph.bind_node(self.pool.lease(ImageInfo::new_2d(
+            vk::Format::R8G8B8A8_UNORM,
+            window.inner_size().width,
+            window.inner_size().height,
+            vk::ImageUsageFlags::COLOR_ATTACHMENT
+                | vk::ImageUsageFlags::SAMPLED
+                | vk::ImageUsageFlags::STORAGE
+                | vk::ImageUsageFlags::TRANSFER_SRC, // TODO: Make TRANSFER_SRC an "extra flags"
+        ))?);
         let font_atlas_image = render_graph.bind_node(self.font_atlas_image.as_ref().unwrap());
         let display_pos = draw_data.display_pos;
         let framebuffer_scale = draw_data.framebuffer_scale;
 
         for draw_list in draw_data.draw_lists() {
             let indices = cast_slice(draw_list.idx_buffer());
-            let mut index_buf = self
-                .pool
-                .lease(BufferInfo {
-                    size: indices.len() as _,
-                    usage: vk::BufferUsageFlags::INDEX_BUFFER,
-                    can_map: true,
-                })
-                .unwrap();
+            let mut index_buf = self.pool.lease(BufferInfo {
+                size: indices.len() as _,
+                usage: vk::BufferUsageFlags::INDEX_BUFFER,
+                can_map: true,
+            })?;
 
             {
                 Buffer::mapped_slice_mut(&mut index_buf)[0..indices.len()].copy_from_slice(indices);
@@ -123,14 +185,11 @@ impl ImGui {
 
             let vertices = draw_list.vtx_buffer();
             let vertex_buf_len = vertices.len() * 20;
-            let mut vertex_buf = self
-                .pool
-                .lease(BufferInfo {
-                    size: vertex_buf_len as _,
-                    usage: vk::BufferUsageFlags::VERTEX_BUFFER,
-                    can_map: true,
-                })
-                .unwrap();
+            let mut vertex_buf = self.pool.lease(BufferInfo {
+                size: vertex_buf_len as _,
+                usage: vk::BufferUsageFlags::VERTEX_BUFFER,
+                can_map: true,
+            })?;
 
             {
                 let vertex_buf = Buffer::mapped_slice_mut(&mut vertex_buf);
@@ -161,6 +220,8 @@ impl ImGui {
                 })
                 .collect::<Vec<_>>();
 
+            let framebuffer_width = window.inner_size().width as f32;
+            let framebuffer_height = window.inner_size().height as f32;
             let window_width =
                 self.platform.hidpi_factor() as f32 / window.inner_size().width as f32;
             let window_height =
@@ -188,10 +249,13 @@ impl ImGui {
                             (clip_rect[2] - display_pos[0]) * framebuffer_scale[0],
                             (clip_rect[3] - display_pos[1]) * framebuffer_scale[1],
                         ];
-                        let x = clip_rect[0].floor() as i32;
-                        let y = clip_rect[1].floor() as i32;
-                        let width = (clip_rect[2] - clip_rect[0]).ceil() as u32;
-                        let height = (clip_rect[3] - clip_rect[1]).ceil() as u32;
+
+                        let (x, y, width, height) =
+                            match clamp_scissor(clip_rect, framebuffer_width, framebuffer_height) {
+                                Some(scissor) => scissor,
+                                None => continue,
+                            };
+
                         subpass.set_scissor(x, y, width, height);
                         subpass.draw_indexed(
                             index_count as _,
@@ -204,14 +268,14 @@ impl ImGui {
                 });
         }
 
-        image
+        Ok(image)
     }
 
     pub fn draw_frame(
         &mut self,
         frame: &mut FrameContext<'_>,
         ui_func: impl FnOnce(&mut Ui),
-    ) -> ImageLeaseNode {
+    ) -> Result<ImageLeaseNode, ImGuiError> {
         self.draw(
             frame.dt,
             frame.events,
@@ -221,9 +285,16 @@ impl ImGui {
         )
     }
 
-    fn lease_font_atlas_image(&mut self, render_graph: &mut RenderGraph) {
+    fn lease_font_atlas_image(
+        &mut self,
+        render_graph: &mut RenderGraph,
+    ) -> Result<(), DriverError> {
         use imgui::{FontConfig, FontGlyphRanges, FontSource};
 
+        // The fonts are rebuilt below; drop the old atlas first so that if leasing fails the next
+        // frame tries again instead of sampling glyphs from a stale texture
+        self.font_atlas_image = None;
+
         let hidpi_factor = self.platform.hidpi_factor();
         self.context.io_mut().font_global_scale = (1.0 / hidpi_factor) as f32;
 
@@ -255,14 +326,11 @@ impl ImGui {
 
         let texture = fonts.build_rgba32_texture(); // TODO: Fix fb channel writes and use alpha8!
         let temp_buf_len = texture.data.len();
-        let mut temp_buf = self
-            .pool
-            .lease(BufferInfo {
-                size: temp_buf_len as _,
-                usage: vk::BufferUsageFlags::TRANSFER_SRC,
-                can_map: true,
-            })
-            .unwrap();
+        let mut temp_buf = self.pool.lease(BufferInfo {
+            size: temp_buf_len as _,
+            usage: vk::BufferUsageFlags::TRANSFER_SRC,
+            can_map: true,
+        })?;
 
         {
             let temp_buf = Buffer::mapped_slice_mut(&mut temp_buf);
@@ -270,21 +338,103 @@ impl ImGui {
         }
 
         let temp_buf = render_graph.bind_node(temp_buf);
-        let image = render_graph.bind_node(
-            self.pool
-                .lease(ImageInfo::new_2d(
-                    vk::Format::R8G8B8A8_UNORM,
-                    texture.width,
-                    texture.height,
-                    vk::ImageUsageFlags::SAMPLED
-                        | vk::ImageUsageFlags::STORAGE
-                        | vk::ImageUsageFlags::TRANSFER_DST,
-                ))
-                .unwrap(),
-        );
+        let image = render_graph.bind_node(self.pool.lease(ImageInfo::new_2d(
+            vk::Format::R8G8B8A8_UNORM,
+            texture.width,
+            texture.height,
+            vk::ImageUsageFlags::SAMPLED
+                | vk::ImageUsageFlags::STORAGE
+                | vk::ImageUsageFlags::TRANSFER_DST,
+        ))?);
 
         render_graph.copy_buffer_to_image(temp_buf, image);
 
         self.font_atlas_image = Some(render_graph.unbind_node(image));
+
+        Ok(())
+    }
+}
+
+/// Errors which may occur while drawing the user interface.
+#[derive(Debug)]
+pub enum ImGuiError {
+    /// Recoverable driver error
+    Driver(DriverError),
+
+    /// The window could not be prepared for the frame, such as when setting the cursor position
+    Window(ExternalError),
+}
+
+impl Error for ImGuiError {}
+
+impl From<DriverError> for ImGuiError {
+    fn from(err: DriverError) -> Self {
+        Self::Driver(err)
+    }
+}
+
+impl From<ExternalError> for ImGuiError {
+    fn from(err: ExternalError) -> Self {
+        Self::Window(err)
+    }
+}
+
+impl std::fmt::Display for ImGuiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Driver(err) => write!(f, "{err}"),
+            Self::Window(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_scissor_inside() {
+        assert_eq!(
+            clamp_scissor([10.0, 20.0, 30.0, 40.0], 100.0, 50.0),
+            Some((10, 20, 20, 20))
+        );
+    }
+
+    #[test]
+    fn clamp_scissor_outside() {
+        assert_eq!(clamp_scissor([-20.0, 0.0, -10.0, 50.0], 100.0, 50.0), None);
+        assert_eq!(clamp_scissor([0.0, -20.0, 100.0, -10.0], 100.0, 50.0), None);
+        assert_eq!(clamp_scissor([110.0, 0.0, 120.0, 50.0], 100.0, 50.0), None);
+        assert_eq!(clamp_scissor([0.0, 60.0, 100.0, 70.0], 100.0, 50.0), None);
+        assert_eq!(clamp_scissor([10.0, 10.0, 10.0, 20.0], 100.0, 50.0), None);
+    }
+
+    #[test]
+    fn clamp_scissor_partial() {
+        // Left, top, right and bottom edges
+        assert_eq!(
+            clamp_scissor([-10.0, 10.0, 20.0, 20.0], 100.0, 50.0),
+            Some((0, 10, 20, 10))
+        );
+        assert_eq!(
+            clamp_scissor([10.0, -10.0, 20.0, 20.0], 100.0, 50.0),
+            Some((10, 0, 10, 20))
+        );
+        assert_eq!(
+            clamp_scissor([90.0, 10.0, 120.0, 20.0], 100.0, 50.0),
+            Some((90, 10, 10, 10))
+        );
+        assert_eq!(
+            clamp_scissor([10.0, 40.0, 20.0, 60.0], 100.0, 50.0),
+            Some((10, 40, 10, 10))
+        );
+    }
+
+    #[test]
+    fn clamp_scissor_rounds_outward() {
+        assert_eq!(
+            clamp_scissor([10.5, 20.5, 29.5, 39.5], 100.0, 50.0),
+            Some((10, 20, 20, 20))
+        );
     }
 }
@@ -10,8 +10,8 @@
     Enter your "name" to begin:
         cargo run --example debugger
 
-    You see an error message up ahead:
-        thread 'main' panicked at 'run with `RUST_LOG=trace` environment variable ...
+    You see an error message up ahead, but only if you init a logger:
+        🆘 Validation Error: [ VUID-...
 
     Note:
         In your own program you should init a compatible logger of some kind! Also note
@@ -30,13 +30,14 @@ fn main() -> Result<(), screen_13::DisplayError> {
     //pretty_env_logger::init();
 
     /*
-        The code ahead is filled with a dangerous `panic` if you did not install the Vulkan SDK!
+        The code ahead validates nothing at all if you did not install the Vulkan SDK!
 
         You must now choose:
             - If you did not install the SDK, you must goto line 8, above.
             - If you have a recent SDK installed, you may advance the function pointer.
     */
-    EventLoop::new().debug(true).build()?.run(|frame| {
+    let event_loop = EventLoop::new().debug(true).park_on_validation_error(true);
+    event_loop.build()?.run(|frame| {
         /*
             You have now entered the per-frame callback. Everything is happening *so* fast. We just
             executed line two of our program!
@@ -0,0 +1,45 @@
+use {screen_13::prelude::*, std::sync::Arc};
+
+/// This example renders without a window or swapchain and reads the result back on the CPU. It is
+/// suitable for servers generating thumbnails or image-based tests.
+fn main() -> Result<(), DriverError> {
+    pretty_env_logger::init();
+
+    // A headless device does not require a surface or presentation support
+    let device = Arc::new(Device::new(DriverConfig::new().build().unwrap())?);
+    let mut cache = HashPool::new(&device);
+
+    let image = Arc::new(Image::create(
+        &device,
+        ImageInfo::new_2d(
+            vk::Format::R8G8B8A8_UNORM,
+            1,
+            1,
+            vk::ImageUsageFlags::TRANSFER_DST | vk::ImageUsageFlags::TRANSFER_SRC,
+        ),
+    )?);
+    let buffer = Arc::new(Buffer::create(
+        &device,
+        BufferInfo::new_mappable(4, vk::BufferUsageFlags::TRANSFER_DST),
+    )?);
+
+    let mut render_graph = RenderGraph::new();
+    let image_node = render_graph.bind_node(&image);
+    let buffer_node = render_graph.bind_node(&buffer);
+
+    render_graph
+        .clear_color_image_value(image_node, [100u8, 149, 237, 255])
+        .copy_image_to_buffer(image_node, buffer_node);
+    render_graph.resolve().submit(&device.queue, &mut cache)?;
+
+    // There is no display to pace us so we simply wait for all work to finish
+    unsafe { device.device_wait_idle() }.map_err(|_| DriverError::Unsupported)?;
+
+    let readback = Buffer::mapped_slice(&buffer);
+
+    info!("readback: {:?}", readback);
+
+    assert_eq!(readback, [100, 149, 237, 255]);
+
+    Ok(())
+}
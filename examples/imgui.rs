@@ -11,7 +11,7 @@ fn main() -> Result<(), DisplayError> {
     // Screen 13 things we need for this demo
     let event_loop = EventLoop::new().build()?;
     let display = ComputePresenter::new(&event_loop.device)?;
-    let mut imgui = ImGui::new(&event_loop.device);
+    let mut imgui = ImGui::new(&event_loop.device)?;
     let mut pool = LazyPool::new(&event_loop.device);
 
     // Some example state to make the demo more interesting
@@ -58,12 +58,22 @@ fn main() -> Result<(), DisplayError> {
                 });
         });
 
-        // Present "gui_image" on top of "app_image" onto "frame.swapchain"
-        display.present_images(
-            frame.render_graph,
-            gui_image,
-            app_image,
-            frame.swapchain_image,
-        );
+        match gui_image {
+            Ok(gui_image) => {
+                // Present "gui_image" on top of "app_image" onto "frame.swapchain"
+                display.present_images(
+                    frame.render_graph,
+                    gui_image,
+                    app_image,
+                    frame.swapchain_image,
+                );
+            }
+            Err(err) => {
+                // The GUI could not be drawn this frame, so only the app is shown
+                warn!("{err}");
+
+                display.present_image(frame.render_graph, app_image, frame.swapchain_image);
+            }
+        }
     })
 }
@@ -14,7 +14,7 @@ fn main() -> anyhow::Result<()> {
         .window(|builder| builder.with_inner_size(LogicalSize::new(1024.0f64, 768.0f64)))
         .build()?;
     let display = ComputePresenter::new(&event_loop.device)?;
-    let mut imgui = ImGui::new(&event_loop.device);
+    let mut imgui = ImGui::new(&event_loop.device)?;
     let mut image_loader = ImageLoader::new(&event_loop.device)?;
     let mut transition_pipeline = TransitionPipeline::new(&event_loop.device);
 
@@ -99,13 +99,22 @@ fn main() -> anyhow::Result<()> {
                 });
         });
 
-        // Display the GUI + Blend images on screen
-        display.present_images(
-            frame.render_graph,
-            gui_image,
-            blend_image,
-            frame.swapchain_image,
-        );
+        match gui_image {
+            Ok(gui_image) => {
+                // Display the GUI + Blend images on screen
+                display.present_images(
+                    frame.render_graph,
+                    gui_image,
+                    blend_image,
+                    frame.swapchain_image,
+                );
+            }
+            Err(err) => {
+                warn!("{err}");
+
+                display.present_image(frame.render_graph, blend_image, frame.swapchain_image);
+            }
+        }
     })?;
 
     Ok(())
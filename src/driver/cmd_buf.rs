@@ -1,8 +1,8 @@
 use {
     super::{Device, DriverError, QueueFamily},
     ash::vk,
-    log::{trace, warn},
-    std::{fmt::Debug, ops::Deref, sync::Arc, thread::panicking},
+    log::{error, trace, warn},
+    std::{fmt::Debug, ops::Deref, sync::Arc, thread::panicking, time::Duration},
 };
 
 #[derive(Debug)]
@@ -83,6 +83,49 @@ impl CommandBuffer {
     pub fn queue_family_index(this: &Self) -> u32 {
         this.device.queue.family.idx
     }
+
+    /// Blocks until all of the given command buffers have finished executing or `timeout` has
+    /// elapsed, returning `true` if they finished and `false` if the wait timed out.
+    ///
+    /// This issues a single wait on all fences, which is cheaper than waiting on each command
+    /// buffer in turn. All command buffers must have been created by the same device. Use
+    /// `Duration::MAX` to wait without a timeout.
+    ///
+    /// Returns `DriverError::InvalidData` if the device is lost.
+    pub fn wait_until_executed(cmd_bufs: &[&Self], timeout: Duration) -> Result<bool, DriverError> {
+        let device = match cmd_bufs.first() {
+            Some(cmd_buf) => &cmd_buf.device,
+            None => return Ok(true),
+        };
+
+        debug_assert!(
+            cmd_bufs
+                .iter()
+                .all(|cmd_buf| Arc::ptr_eq(&cmd_buf.device, device)),
+            "command buffers must share a device"
+        );
+
+        let fences = cmd_bufs
+            .iter()
+            .map(|cmd_buf| cmd_buf.fence)
+            .collect::<Vec<_>>();
+        let timeout = u64::try_from(timeout.as_nanos()).unwrap_or(u64::MAX);
+
+        match unsafe { device.wait_for_fences(&fences, true, timeout) } {
+            Ok(_) => Ok(true),
+            Err(err) if err == vk::Result::TIMEOUT => Ok(false),
+            Err(err) if err == vk::Result::ERROR_DEVICE_LOST => {
+                error!("Device lost");
+
+                Err(DriverError::InvalidData)
+            }
+            Err(err) => {
+                warn!("{err}");
+
+                Err(DriverError::OutOfMemory)
+            }
+        }
+    }
 }
 
 impl Deref for CommandBuffer {
@@ -30,7 +30,7 @@ pub struct Device {
     pub(super) allocator: Option<Mutex<Allocator>>,
     pub descriptor_indexing_features: PhysicalDeviceDescriptorIndexingFeatures,
     device: ash::Device,
-    immutable_samplers: HashMap<SamplerDesc, vk::Sampler>,
+    samplers: Mutex<HashMap<SamplerDesc, vk::Sampler>>,
     pub instance: Arc<Instance>, // TODO: Need shared?
     pub physical_device: PhysicalDevice,
     pub queue: Queue,
@@ -41,10 +41,19 @@ pub struct Device {
 }
 
 impl Device {
+    /// Creates a headless device which is suitable for offscreen rendering and readback.
+    ///
+    /// No window surface is required and the selected device and queue do not need presentation
+    /// support. Headless devices never enable the swapchain extension, so `cfg.presentation` has
+    /// no effect here.
     pub fn new(cfg: DriverConfig) -> Result<Self, DriverError> {
         trace!("new {:?}", cfg);
 
-        let instance = Arc::new(Instance::new(cfg.debug, empty())?);
+        let instance = Arc::new(Instance::new(
+            cfg.debug,
+            cfg.park_on_validation_error,
+            empty(),
+        )?);
         let physical_device = Instance::physical_devices(&instance)?
             .filter(|physical_device| {
                 if cfg.ray_tracing && !PhysicalDevice::has_ray_tracing_support(physical_device) {
@@ -67,7 +76,15 @@ impl Device {
             .max_by_key(PhysicalDevice::score_device_type)
             .ok_or(DriverError::Unsupported)?;
 
-        Device::create(&instance, physical_device, cfg)
+        // There is no surface instance extension so we must not enable the swapchain either
+        Device::create(
+            &instance,
+            physical_device,
+            DriverConfig {
+                presentation: false,
+                ..cfg
+            },
+        )
     }
 
     pub fn create(
@@ -122,15 +139,16 @@ impl Device {
 
         let priorities = [1.0];
         let queue = PhysicalDevice::queue_families(&physical_device).find(|qf| {
-            qf.props.queue_flags.contains(
-                vk::QueueFlags::COMPUTE & vk::QueueFlags::GRAPHICS & vk::QueueFlags::TRANSFER,
-            )
+            // Graphics and compute queues implicitly support transfer operations
+            qf.props
+                .queue_flags
+                .contains(vk::QueueFlags::COMPUTE | vk::QueueFlags::GRAPHICS)
         });
 
         let queue = if let Some(queue) = queue {
             queue
         } else {
-            warn!("no suitable presentation queue found");
+            warn!("no suitable queue found");
 
             return Err(DriverError::Unsupported);
         };
@@ -334,8 +352,6 @@ impl Device {
                 family: queue,
             };
 
-            let immutable_samplers = Self::create_immutable_samplers(&device)?;
-
             let (surface_ext, swapchain_ext) = if cfg.presentation {
                 (
                     Some(khr::Surface::new(&instance.entry, &instance)),
@@ -361,81 +377,67 @@ impl Device {
                 allocator: Some(Mutex::new(allocator)),
                 descriptor_indexing_features,
                 device,
-                immutable_samplers,
                 instance,
                 physical_device,
                 queue,
                 ray_tracing_pipeline_ext,
                 ray_tracing_pipeline_properties,
+                samplers: Default::default(),
                 surface_ext,
                 swapchain_ext,
             })
         }
     }
 
-    fn create_immutable_samplers(
-        device: &ash::Device,
-    ) -> Result<HashMap<SamplerDesc, vk::Sampler>, DriverError> {
-        let texel_filters = [vk::Filter::LINEAR, vk::Filter::NEAREST];
-        let mipmap_modes = [
-            vk::SamplerMipmapMode::LINEAR,
-            vk::SamplerMipmapMode::NEAREST,
-        ];
-        let address_modes = [
-            vk::SamplerAddressMode::CLAMP_TO_BORDER,
-            vk::SamplerAddressMode::CLAMP_TO_EDGE,
-            vk::SamplerAddressMode::MIRRORED_REPEAT,
-            vk::SamplerAddressMode::REPEAT,
-        ];
-
-        let mut res = HashMap::new();
-
-        for texel_filter in texel_filters {
-            for mipmap_mode in mipmap_modes {
-                for address_modes in address_modes {
-                    let anisotropy_enable = texel_filter == vk::Filter::LINEAR;
-
-                    res.insert(
-                        SamplerDesc {
-                            texel_filter,
-                            mipmap_mode,
-                            address_modes,
-                        },
-                        unsafe {
-                            let mut info = vk::SamplerCreateInfo::builder()
-                                .mag_filter(texel_filter)
-                                .min_filter(texel_filter)
-                                .mipmap_mode(mipmap_mode)
-                                .address_mode_u(address_modes)
-                                .address_mode_v(address_modes)
-                                .address_mode_w(address_modes)
-                                .max_lod(vk::LOD_CLAMP_NONE)
-                                .anisotropy_enable(anisotropy_enable);
-
-                            if anisotropy_enable {
-                                info = info.max_anisotropy(16.0);
-                            }
-
-                            device.create_sampler(&info, None)
-                        }
-                        .map_err(|err| {
-                            warn!("{err}");
-
-                            DriverError::Unsupported
-                        })?,
-                    );
-                }
-            }
+    /// Returns a shared sampler for the given description, creating it on first use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sampler cannot be created; use [`Device::sampler`] to handle the error.
+    pub fn immutable_sampler(this: &Self, info: SamplerDesc) -> vk::Sampler {
+        Self::sampler(this, info).unwrap_or_else(|_| panic!("Unable to create sampler {info:?}"))
+    }
+
+    /// Returns a shared sampler for the given description, creating it on first use.
+    ///
+    /// Samplers are owned by the device and remain valid until it is dropped.
+    pub fn sampler(this: &Self, info: SamplerDesc) -> Result<vk::Sampler, DriverError> {
+        let mut samplers = this.samplers.lock();
+
+        if let Some(sampler) = samplers.get(&info) {
+            return Ok(*sampler);
         }
 
-        Ok(res)
-    }
+        let mut create_info = vk::SamplerCreateInfo::builder()
+            .mag_filter(info.texel_filter)
+            .min_filter(info.texel_filter)
+            .mipmap_mode(info.mipmap_mode)
+            .address_mode_u(info.address_modes)
+            .address_mode_v(info.address_modes)
+            .address_mode_w(info.address_modes)
+            .max_lod(vk::LOD_CLAMP_NONE);
+
+        if let Some(anisotropy) = info.anisotropy {
+            create_info = create_info.anisotropy_enable(true).max_anisotropy(
+                anisotropy
+                    .0
+                    .min(this.physical_device.props.limits.max_sampler_anisotropy),
+            );
+        }
 
-    pub fn immutable_sampler(this: &Self, info: SamplerDesc) -> vk::Sampler {
-        this.immutable_samplers
-            .get(&info)
-            .copied()
-            .unwrap_or_else(|| unimplemented!("{:?}", info))
+        if let Some(compare_op) = info.compare_op {
+            create_info = create_info.compare_enable(true).compare_op(compare_op);
+        }
+
+        let sampler = unsafe { this.device.create_sampler(&create_info, None) }.map_err(|err| {
+            warn!("{err}");
+
+            DriverError::Unsupported
+        })?;
+
+        samplers.insert(info, sampler);
+
+        Ok(sampler)
     }
 
     pub fn surface_formats(
@@ -533,7 +535,7 @@ impl Drop for Device {
 
         self.allocator.take().unwrap();
 
-        for (_, sampler) in self.immutable_samplers.drain() {
+        for (_, sampler) in self.samplers.get_mut().drain() {
             unsafe {
                 self.device.destroy_sampler(sampler, None);
             }
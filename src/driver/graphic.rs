@@ -49,6 +49,7 @@ impl BlendModeBuilder {
 }
 
 impl BlendMode {
+    /// Writes source values without blending.
     pub const REPLACE: Self = Self {
         blend_enable: false,
         src_color_blend_factor: vk::BlendFactor::SRC_COLOR,
@@ -59,6 +60,7 @@ impl BlendMode {
         alpha_blend_op: vk::BlendOp::ADD,
         color_write_mask: RGBA_COLOR_COMPONENTS,
     };
+    /// Blends sources with straight (non-premultiplied) alpha.
     pub const ALPHA: Self = Self {
         blend_enable: true,
         src_color_blend_factor: vk::BlendFactor::SRC_ALPHA,
@@ -69,13 +71,14 @@ impl BlendMode {
         alpha_blend_op: vk::BlendOp::ADD,
         color_write_mask: RGBA_COLOR_COMPONENTS,
     };
+    /// Blends sources whose color values have already been multiplied by alpha.
     pub const PRE_MULTIPLIED_ALPHA: Self = Self {
         blend_enable: true,
-        src_color_blend_factor: vk::BlendFactor::SRC_ALPHA,
+        src_color_blend_factor: vk::BlendFactor::ONE,
         dst_color_blend_factor: vk::BlendFactor::ONE_MINUS_SRC_ALPHA,
         color_blend_op: vk::BlendOp::ADD,
         src_alpha_blend_factor: vk::BlendFactor::ONE,
-        dst_alpha_blend_factor: vk::BlendFactor::ONE,
+        dst_alpha_blend_factor: vk::BlendFactor::ONE_MINUS_SRC_ALPHA,
         alpha_blend_op: vk::BlendOp::ADD,
         color_write_mask: RGBA_COLOR_COMPONENTS,
     };
@@ -110,6 +113,32 @@ impl Default for BlendMode {
     }
 }
 
+/// Offsets the depth values of rasterized polygons, which is useful for avoiding shadow acne and
+/// z-fighting between coplanar geometry such as decals.
+///
+/// The `clamp` value must be `0.0` unless the device supports the `depthBiasClamp` feature.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DepthBias {
+    /// Limits the maximum (or minimum, if negative) depth bias of a fragment.
+    pub clamp: OrderedFloat<f32>,
+
+    /// Constant depth value added to each fragment.
+    pub constant_factor: OrderedFloat<f32>,
+
+    /// Scalar applied to the slope of each fragment.
+    pub slope_factor: OrderedFloat<f32>,
+}
+
+impl DepthBias {
+    pub fn new(constant_factor: f32, slope_factor: f32, clamp: f32) -> Self {
+        Self {
+            clamp: OrderedFloat(clamp),
+            constant_factor: OrderedFloat(constant_factor),
+            slope_factor: OrderedFloat(slope_factor),
+        }
+    }
+}
+
 #[derive(Builder, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[builder(
     build_fn(private, name = "fallible_build"),
@@ -118,12 +147,18 @@ impl Default for BlendMode {
 )]
 pub struct DepthStencilMode {
     pub back: StencilMode,
+    /// Discards fragments where the existing depth value is outside of `min..=max`.
+    ///
+    /// Requires the `depthBounds` device feature, which is enabled whenever the device supports
+    /// it. This is useful for rejecting light volume fragments which cannot affect the scene.
     pub bounds_test: bool,
     pub compare_op: vk::CompareOp,
     pub depth_test: bool,
     pub depth_write: bool,
     pub front: StencilMode,
+    /// Minimum depth value used by `bounds_test`.
     pub min: OrderedFloat<f32>,
+    /// Maximum depth value used by `bounds_test`.
     pub max: OrderedFloat<f32>,
     pub stencil_test: bool,
 }
@@ -258,7 +293,8 @@ impl GraphicPipeline {
         let mut descriptor_bindings = Shader::merge_descriptor_bindings(
             shaders
                 .iter()
-                .map(|shader| shader.descriptor_bindings(&device)),
+                .map(|shader| shader.descriptor_bindings(&device))
+                .collect::<Result<Vec<_>, _>>()?,
         );
         for (descriptor_info, _) in descriptor_bindings.values_mut() {
             if descriptor_info.binding_count() == 0 {
@@ -422,6 +458,10 @@ pub struct GraphicPipelineInfo {
     #[builder(default = "vk::CullModeFlags::BACK")]
     pub cull_mode: vk::CullModeFlags,
 
+    /// Polygon depth bias applied during rasterization, if any.
+    #[builder(default, setter(strip_option))]
+    pub depth_bias: Option<DepthBias>,
+
     #[builder(default, setter(strip_option))]
     pub depth_stencil: Option<DepthStencilMode>,
 
@@ -564,6 +564,16 @@ impl ImageView {
         image: &Image,
     ) -> Result<Self, DriverError> {
         let info = info.into();
+
+        debug_assert!(
+            info.fmt == image.info.fmt
+                || image
+                    .info
+                    .flags
+                    .contains(vk::ImageCreateFlags::MUTABLE_FORMAT),
+            "view format differs from image format but image is not MUTABLE_FORMAT"
+        );
+
         let device = Arc::clone(device);
         let create_info = vk::ImageViewCreateInfo {
             s_type: vk::StructureType::IMAGE_VIEW_CREATE_INFO,
@@ -629,7 +639,14 @@ pub struct ImageViewInfo {
     pub aspect_mask: vk::ImageAspectFlags,
     pub base_array_layer: u32,
     pub base_mip_level: u32,
+
+    /// The format of the view, which is usually the format of the image.
+    ///
+    /// Images created with `vk::ImageCreateFlags::MUTABLE_FORMAT` may be viewed using any format
+    /// of the same compatibility class; for example an `R8G8B8A8_SRGB` view of an
+    /// `R8G8B8A8_UNORM` image encodes and decodes sRGB when rendering into or sampling it.
     pub fmt: vk::Format,
+
     pub mip_level_count: Option<u32>,
     pub ty: ImageType,
 }
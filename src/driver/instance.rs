@@ -1,63 +1,66 @@
 use {
     super::{DriverError, PhysicalDevice, QueueFamily, QueueFamilyProperties},
     ash::{extensions::ext, vk, Entry},
-    log::{debug, error, info, logger, trace, warn, Level, Metadata},
+    log::{debug, error, info, logger, trace, warn},
     std::{
-        env::var,
         ffi::{c_void, CStr, CString},
         fmt::{Debug, Formatter},
         ops::Deref,
-        os::raw::c_char,
         process::id,
         thread::{current, panicking, park},
     },
 };
 
-unsafe extern "system" fn vulkan_debug_callback(
-    _flags: vk::DebugReportFlagsEXT,
-    _obj_type: vk::DebugReportObjectTypeEXT,
-    _src_obj: u64,
-    _location: usize,
-    _msg_code: i32,
-    _layer_prefix: *const c_char,
-    message: *const c_char,
-    _user_data: *mut c_void,
-) -> u32 {
-    let message = CStr::from_ptr(message).to_str().unwrap();
-
-    if message.starts_with("Validation Warning: [ UNASSIGNED-BestPractices-pipeline-stage-flags ]")
-    {
-        // vk_sync uses vk::PipelineStageFlags::ALL_COMMANDS with AccessType::NOTHING and others
-        warn!("{}", message);
+const VALIDATION_LAYER_NAME: &str = "VK_LAYER_KHRONOS_validation";
+
+/// Forwards a debug utils message to the `log` crate at the matching level, returning `true` if
+/// the message is an error.
+unsafe fn log_debug_message(
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT,
+) -> bool {
+    let callback_data = &*callback_data;
+    let message = if callback_data.p_message.is_null() {
+        Default::default()
     } else {
-        let prefix = "Validation Error: [ ";
+        CStr::from_ptr(callback_data.p_message).to_string_lossy()
+    };
 
-        let (vuid, message) = if message.starts_with(prefix) {
-            let (vuid, message) = message
-                .trim_start_matches(prefix)
-                .split_once(" ]")
-                .unwrap_or_default();
-            let message = message.split(" | ").nth(2).unwrap_or(message);
+    if message_severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::ERROR) {
+        error!("🆘 {message}");
 
-            (Some(vuid.trim()), message)
+        true
+    } else {
+        if message_severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::WARNING) {
+            warn!("{message}");
+        } else if message_severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::INFO) {
+            info!("{message}");
         } else {
-            (None, message)
-        };
-
-        if let Some(vuid) = vuid {
-            info!("{vuid}");
+            trace!("{message}");
         }
 
-        error!("🆘 {message}");
+        false
+    }
+}
 
-        if !logger().enabled(&Metadata::builder().level(Level::Debug).build())
-            || var("RUST_LOG")
-                .map(|rust_log| rust_log.is_empty())
-                .unwrap_or(true)
-        {
-            panic!("run with `RUST_LOG=trace` environment variable to display more information - see https://github.com/rust-lang/log#in-executables");
-        }
+unsafe extern "system" fn vulkan_debug_callback(
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    _message_type: vk::DebugUtilsMessageTypeFlagsEXT,
+    callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT,
+    _user_data: *mut c_void,
+) -> vk::Bool32 {
+    log_debug_message(message_severity, callback_data);
 
+    vk::FALSE
+}
+
+unsafe extern "system" fn vulkan_debug_callback_park(
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    _message_type: vk::DebugUtilsMessageTypeFlagsEXT,
+    callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT,
+    _user_data: *mut c_void,
+) -> vk::Bool32 {
+    if log_debug_message(message_severity, callback_data) {
         if current().name() != Some("main") {
             warn!("executing on a child thread!")
         }
@@ -77,17 +80,22 @@ unsafe extern "system" fn vulkan_debug_callback(
 }
 
 pub struct Instance {
-    _debug_callback: Option<vk::DebugReportCallbackEXT>,
-    #[allow(deprecated)] // TODO: Remove? Look into this....
-    _debug_loader: Option<ext::DebugReport>,
-    _debug_utils: Option<ext::DebugUtils>,
+    debug_messenger: Option<vk::DebugUtilsMessengerEXT>,
+    debug_utils: Option<ext::DebugUtils>,
     pub entry: Entry,
     instance: ash::Instance,
 }
 
 impl Instance {
+    /// Creates a new Vulkan instance.
+    ///
+    /// When `debug` is set and the validation layer is installed, validation messages are
+    /// forwarded to the `log` crate at the matching level. If `park_on_validation_error` is also
+    /// set, the thread which caused a validation error is parked so that a debugger may be
+    /// attached.
     pub fn new<'a>(
         debug: bool,
+        park_on_validation_error: bool,
         required_extensions: impl Iterator<Item = &'a CStr>,
     ) -> Result<Self, DriverError> {
         #[cfg(not(target_os = "macos"))]
@@ -110,6 +118,19 @@ impl Instance {
 
             entry
         };
+
+        // Validation layers are only present with a Vulkan SDK installation; run without them
+        // instead of failing to create an instance
+        let debug = debug && {
+            let has_validation_layer = Self::has_validation_layer(&entry);
+
+            if !has_validation_layer {
+                warn!("{VALIDATION_LAYER_NAME} not found: debug disabled");
+            }
+
+            has_validation_layer
+        };
+
         let required_extensions = required_extensions.collect::<Vec<_>>();
         let instance_extensions = required_extensions
             .iter()
@@ -149,58 +170,83 @@ impl Instance {
 
         trace!("Created a Vulkan instance");
 
-        let (debug_loader, debug_callback, debug_utils) = if debug {
-            let debug_info = vk::DebugReportCallbackCreateInfoEXT {
-                flags: vk::DebugReportFlagsEXT::ERROR
-                    | vk::DebugReportFlagsEXT::WARNING
-                    | vk::DebugReportFlagsEXT::PERFORMANCE_WARNING,
-                pfn_callback: Some(vulkan_debug_callback),
-                ..Default::default()
-            };
+        let (debug_messenger, debug_utils) = if debug {
+            let debug_info = vk::DebugUtilsMessengerCreateInfoEXT::builder()
+                .message_severity(
+                    vk::DebugUtilsMessageSeverityFlagsEXT::VERBOSE
+                        | vk::DebugUtilsMessageSeverityFlagsEXT::INFO
+                        | vk::DebugUtilsMessageSeverityFlagsEXT::WARNING
+                        | vk::DebugUtilsMessageSeverityFlagsEXT::ERROR,
+                )
+                .message_type(
+                    vk::DebugUtilsMessageTypeFlagsEXT::GENERAL
+                        | vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION
+                        | vk::DebugUtilsMessageTypeFlagsEXT::PERFORMANCE,
+                )
+                .pfn_user_callback(Some(if park_on_validation_error {
+                    vulkan_debug_callback_park
+                } else {
+                    vulkan_debug_callback
+                }));
+            let debug_utils = ext::DebugUtils::new(&entry, &instance);
+            let debug_messenger = unsafe {
+                debug_utils
+                    .create_debug_utils_messenger(&debug_info, None)
+                    .map_err(|err| {
+                        warn!("{err}");
 
-            #[allow(deprecated)]
-            let debug_loader = ext::DebugReport::new(&entry, &instance);
+                        instance.destroy_instance(None);
 
-            let debug_callback = unsafe {
-                #[allow(deprecated)]
-                debug_loader
-                    .create_debug_report_callback(&debug_info, None)
-                    .unwrap()
+                        DriverError::Unsupported
+                    })?
             };
 
-            let debug_utils = ext::DebugUtils::new(&entry, &instance);
-
-            (Some(debug_loader), Some(debug_callback), Some(debug_utils))
+            (Some(debug_messenger), Some(debug_utils))
         } else {
-            (None, None, None)
+            (None, None)
         };
 
         Ok(Self {
-            _debug_callback: debug_callback,
-            _debug_loader: debug_loader,
-            _debug_utils: debug_utils,
+            debug_messenger,
+            debug_utils,
             entry,
             instance,
         })
     }
 
+    /// Returns `true` if validation messages are being forwarded to the `log` crate.
+    pub fn is_debug(this: &Self) -> bool {
+        this.debug_messenger.is_some()
+    }
+
     unsafe fn extension_names(debug: bool) -> Vec<*const i8> {
         let mut res = vec![];
 
         if debug {
-            #[allow(deprecated)]
-            res.push(ext::DebugReport::name().as_ptr());
-            res.push(vk::ExtDebugUtilsFn::name().as_ptr());
+            res.push(ext::DebugUtils::name().as_ptr());
         }
 
         res
     }
 
+    fn has_validation_layer(entry: &Entry) -> bool {
+        entry
+            .enumerate_instance_layer_properties()
+            .map(|layers| {
+                layers.iter().any(|layer| {
+                    let layer_name = unsafe { CStr::from_ptr(layer.layer_name.as_ptr()) };
+
+                    layer_name.to_str() == Ok(VALIDATION_LAYER_NAME)
+                })
+            })
+            .unwrap_or_default()
+    }
+
     fn layer_names(debug: bool) -> Vec<CString> {
         let mut res = Vec::new();
 
         if debug {
-            if let Ok(name) = CString::new("VK_LAYER_KHRONOS_validation") {
+            if let Ok(name) = CString::new(VALIDATION_LAYER_NAME) {
                 res.push(name);
             }
         }
@@ -275,13 +321,75 @@ impl Drop for Instance {
         }
 
         unsafe {
-            #[allow(deprecated)]
-            if let Some(debug_loader) = &self._debug_loader {
-                let debug_callback = self._debug_callback.unwrap();
-                debug_loader.destroy_debug_report_callback(debug_callback, None);
+            if let Some(debug_utils) = &self.debug_utils {
+                let debug_messenger = self.debug_messenger.unwrap();
+                debug_utils.destroy_debug_utils_messenger(debug_messenger, None);
             }
 
             self.instance.destroy_instance(None);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::driver::{Device, DriverConfig},
+        log::{LevelFilter, Log, Metadata, Record},
+        std::sync::Mutex,
+    };
+
+    static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct CaptureLogger;
+
+    impl Log for CaptureLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            MESSAGES.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn debug_messenger_logs_validation_errors() {
+        static LOGGER: CaptureLogger = CaptureLogger;
+
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+
+        // Without a Vulkan driver or the validation layer there is nothing to test
+        let device = match Device::new(
+            DriverConfig::new()
+                .debug(true)
+                .presentation(false)
+                .build()
+                .unwrap(),
+        ) {
+            Ok(device) if Instance::is_debug(&device.instance) => device,
+            _ => return,
+        };
+
+        unsafe {
+            // A zero-sized buffer is invalid
+            let buffer_info = vk::BufferCreateInfo::builder()
+                .size(0)
+                .usage(vk::BufferUsageFlags::TRANSFER_SRC);
+
+            if let Ok(buffer) = device.create_buffer(&buffer_info, None) {
+                device.destroy_buffer(buffer, None);
+            }
+        }
+
+        assert!(MESSAGES
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|message| message.contains("VUID-VkBufferCreateInfo-size-00912")));
+    }
+}
@@ -30,7 +30,7 @@ pub use {
         descriptor_set_layout::DescriptorSetLayout,
         device::{Device, FeatureFlags},
         graphic::{
-            BlendMode, DepthStencilMode, GraphicPipeline, GraphicPipelineInfo,
+            BlendMode, DepthBias, DepthStencilMode, GraphicPipeline, GraphicPipelineInfo,
             GraphicPipelineInfoBuilder, StencilMode, VertexInputState,
         },
         image::{
@@ -64,6 +64,7 @@ pub use {
 use {
     derive_builder::Builder,
     log::{debug, info, trace, warn},
+    ordered_float::OrderedFloat,
     raw_window_handle::HasRawWindowHandle,
     std::{
         cmp::Ordering,
@@ -262,6 +263,52 @@ pub const fn format_aspect_mask(fmt: vk::Format) -> vk::ImageAspectFlags {
     }
 }
 
+/// Returns the size, in bytes, of one texel of an uncompressed color format, or `None` for depth,
+/// stencil, compressed and other formats.
+pub(crate) const fn format_texel_block_size(fmt: vk::Format) -> Option<u32> {
+    Some(match fmt.as_raw() {
+        // R4G4_UNORM_PACK8
+        1 => 1,
+        // R4G4B4A4_UNORM_PACK16..=A1R5G5B5_UNORM_PACK16
+        2..=8 => 2,
+        // R8_UNORM..=R8_SRGB
+        9..=15 => 1,
+        // R8G8_UNORM..=R8G8_SRGB
+        16..=22 => 2,
+        // R8G8B8_UNORM..=B8G8R8_SRGB
+        23..=36 => 3,
+        // R8G8B8A8_UNORM..=A2B10G10R10_SINT_PACK32
+        37..=69 => 4,
+        // R16_UNORM..=R16_SFLOAT
+        70..=76 => 2,
+        // R16G16_UNORM..=R16G16_SFLOAT
+        77..=83 => 4,
+        // R16G16B16_UNORM..=R16G16B16_SFLOAT
+        84..=90 => 6,
+        // R16G16B16A16_UNORM..=R16G16B16A16_SFLOAT
+        91..=97 => 8,
+        // R32_UINT..=R32_SFLOAT
+        98..=100 => 4,
+        // R32G32_UINT..=R32G32_SFLOAT
+        101..=103 => 8,
+        // R32G32B32_UINT..=R32G32B32_SFLOAT
+        104..=106 => 12,
+        // R32G32B32A32_UINT..=R32G32B32A32_SFLOAT
+        107..=109 => 16,
+        // R64_UINT..=R64_SFLOAT
+        110..=112 => 8,
+        // R64G64_UINT..=R64G64_SFLOAT
+        113..=115 => 16,
+        // R64G64B64_UINT..=R64G64B64_SFLOAT
+        116..=118 => 24,
+        // R64G64B64A64_UINT..=R64G64B64A64_SFLOAT
+        119..=121 => 32,
+        // B10G11R11_UFLOAT_PACK32 and E5B9G9R9_UFLOAT_PACK32
+        122..=123 => 4,
+        _ => return None,
+    })
+}
+
 pub const fn image_access_layout(access: AccessType) -> ImageLayout {
     if matches!(access, AccessType::Present | AccessType::ComputeShaderWrite) {
         ImageLayout::General
@@ -591,7 +638,11 @@ impl Driver {
             })?
             .iter()
             .map(|ext| unsafe { CStr::from_ptr(*ext as *const _) });
-        let instance = Arc::new(Instance::new(cfg.debug, required_extensions)?);
+        let instance = Arc::new(Instance::new(
+            cfg.debug,
+            cfg.park_on_validation_error,
+            required_extensions,
+        )?);
         let surface = Surface::new(&instance, window)?;
         let physical_devices = Instance::physical_devices(&instance)?
             .filter(|physical_device| {
@@ -681,8 +732,9 @@ impl Driver {
 pub struct DriverConfig {
     /// Enables Vulkan validation layers.
     ///
-    /// This requires a Vulkan SDK installation and will cause validation errors to introduce
-    /// panics as they happen.
+    /// This requires a Vulkan SDK installation. Validation messages are logged using the `log`
+    /// crate at the level matching their severity. If the validation layer is not installed a
+    /// warning is logged and debug is disabled.
     ///
     /// _NOTE:_ Consider turning OFF debug if you discover an unknown issue. Often the validation
     /// layers will throw an error before other layers can provide additional context such as the
@@ -694,6 +746,13 @@ pub struct DriverConfig {
     #[builder(default = "3")]
     pub desired_swapchain_image_count: u32,
 
+    /// Parks the thread which caused a validation error after it has been logged, which makes it
+    /// easy to attach a debugger and see what is causing the issue directly.
+    ///
+    /// Has no effect unless `debug` is enabled.
+    #[builder(default)]
+    pub park_on_validation_error: bool,
+
     /// Determines if frames will be submitted to the display in a synchronous fashion or if they
     /// should be displayed as fast as possible instead.
     ///
@@ -701,6 +760,9 @@ pub struct DriverConfig {
     #[builder(default = "true")]
     pub sync_display: bool,
 
+    /// Enables the swapchain extension so that images may be presented to a window surface.
+    ///
+    /// Headless devices created using `Device::new` never support presentation.
     #[builder(default = "true")]
     pub presentation: bool,
 
@@ -716,7 +778,7 @@ impl DriverConfig {
 
     fn features(self) -> FeatureFlags {
         FeatureFlags {
-            presentation: true,
+            presentation: self.presentation,
             ray_tracing: self.ray_tracing,
         }
     }
@@ -869,9 +931,85 @@ impl Deref for Queue {
     }
 }
 
+/// Describes a sampler configuration; samplers are created once per unique description and shared
+/// using `Device::sampler`.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct SamplerDesc {
     pub address_modes: vk::SamplerAddressMode,
+
+    /// Maximum anisotropy value, or `None` to disable anisotropic filtering.
+    ///
+    /// Values are clamped to the `maxSamplerAnisotropy` device limit.
+    pub anisotropy: Option<OrderedFloat<f32>>,
+
+    /// Comparison operator applied to fetched data before filtering, as used by depth (shadow)
+    /// samplers, or `None` to disable comparison.
+    ///
+    /// _NOTE:_ Samplers inferred from shader binding names always use [`SamplerDesc::new`] and so
+    /// never enable comparison.
+    pub compare_op: Option<vk::CompareOp>,
+
     pub mipmap_mode: vk::SamplerMipmapMode,
     pub texel_filter: vk::Filter,
 }
+
+impl SamplerDesc {
+    /// Creates a sampler description with 16x anisotropic filtering for linear filters and no
+    /// depth comparison.
+    pub fn new(
+        texel_filter: vk::Filter,
+        mipmap_mode: vk::SamplerMipmapMode,
+        address_modes: vk::SamplerAddressMode,
+    ) -> Self {
+        Self {
+            address_modes,
+            anisotropy: (texel_filter == vk::Filter::LINEAR).then_some(OrderedFloat(16.0)),
+            compare_op: None,
+            mipmap_mode,
+            texel_filter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_texel_block_size_known() {
+        assert_eq!(
+            format_texel_block_size(vk::Format::R4G4_UNORM_PACK8),
+            Some(1)
+        );
+        assert_eq!(format_texel_block_size(vk::Format::R8_SRGB), Some(1));
+        assert_eq!(format_texel_block_size(vk::Format::R8G8B8_UNORM), Some(3));
+        assert_eq!(format_texel_block_size(vk::Format::R8G8B8A8_UNORM), Some(4));
+        assert_eq!(format_texel_block_size(vk::Format::B8G8R8A8_SRGB), Some(4));
+        assert_eq!(
+            format_texel_block_size(vk::Format::R16G16B16A16_SFLOAT),
+            Some(8)
+        );
+        assert_eq!(
+            format_texel_block_size(vk::Format::R32G32B32A32_SFLOAT),
+            Some(16)
+        );
+        assert_eq!(
+            format_texel_block_size(vk::Format::R64G64B64A64_SFLOAT),
+            Some(32)
+        );
+        assert_eq!(
+            format_texel_block_size(vk::Format::E5B9G9R9_UFLOAT_PACK32),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn format_texel_block_size_unknown() {
+        assert_eq!(format_texel_block_size(vk::Format::UNDEFINED), None);
+        assert_eq!(format_texel_block_size(vk::Format::D32_SFLOAT), None);
+        assert_eq!(
+            format_texel_block_size(vk::Format::BC1_RGB_UNORM_BLOCK),
+            None
+        );
+    }
+}
@@ -53,7 +53,8 @@ impl RayTracePipeline {
         let mut descriptor_bindings = Shader::merge_descriptor_bindings(
             shaders
                 .iter()
-                .map(|shader| shader.descriptor_bindings(device)),
+                .map(|shader| shader.descriptor_bindings(device))
+                .collect::<Result<Vec<_>, _>>()?,
         );
         for (descriptor_info, _) in descriptor_bindings.values_mut() {
             if descriptor_info.binding_count() == 0 {
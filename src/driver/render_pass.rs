@@ -418,6 +418,16 @@ impl RenderPass {
             cull_mode: pipeline.info.cull_mode,
             ..Default::default()
         };
+        let rasterization_state = match pipeline.info.depth_bias {
+            Some(depth_bias) => vk::PipelineRasterizationStateCreateInfo {
+                depth_bias_clamp: *depth_bias.clamp,
+                depth_bias_constant_factor: *depth_bias.constant_factor,
+                depth_bias_enable: vk::TRUE,
+                depth_bias_slope_factor: *depth_bias.slope_factor,
+                ..rasterization_state
+            },
+            None => rasterization_state,
+        };
         let graphic_pipeline_info = vk::GraphicsPipelineCreateInfo::builder()
             .color_blend_state(&color_blend_state)
             .depth_stencil_state(&depth_stencil)
@@ -1,6 +1,7 @@
 use {
     super::{DescriptorSetLayout, Device, DriverError, SamplerDesc, VertexInputState},
     ash::vk,
+    bytemuck::cast_slice,
     derive_builder::Builder,
     log::{debug, error, info, trace},
     spirq::{
@@ -17,7 +18,12 @@ use {
 
 pub type DescriptorBindingMap = BTreeMap<DescriptorBinding, (DescriptorInfo, vk::ShaderStageFlags)>;
 
-fn guess_immutable_sampler(device: &Device, binding_name: &str) -> vk::Sampler {
+// Binding names only specify filtering and addressing; anisotropy and depth comparison are always
+// the defaults of `SamplerDesc::new`
+fn guess_immutable_sampler(
+    device: &Device,
+    binding_name: &str,
+) -> Result<vk::Sampler, DriverError> {
     const INVALID_ERR: &str = "Invalid sampler specification";
 
     let (texel_filter, mipmap_mode, address_modes) = if binding_name.contains("_sampler_") {
@@ -53,13 +59,9 @@ fn guess_immutable_sampler(device: &Device, binding_name: &str) -> vk::Sampler {
         )
     };
 
-    Device::immutable_sampler(
+    Device::sampler(
         device,
-        SamplerDesc {
-            texel_filter,
-            mipmap_mode,
-            address_modes,
-        },
+        SamplerDesc::new(texel_filter, mipmap_mode, address_modes),
     )
 }
 
@@ -376,7 +378,10 @@ impl Shader {
         )
     }
 
-    pub fn descriptor_bindings(&self, device: &Device) -> DescriptorBindingMap {
+    pub fn descriptor_bindings(
+        &self,
+        device: &Device,
+    ) -> Result<DescriptorBindingMap, DriverError> {
         let mut res = DescriptorBindingMap::default();
 
         for (name, binding, desc_ty, binding_count) in
@@ -406,7 +411,10 @@ impl Shader {
                 }
                 DescriptorType::CombinedImageSampler() => DescriptorInfo::CombinedImageSampler(
                     binding_count,
-                    guess_immutable_sampler(device, name.as_deref().expect("invalid binding name")),
+                    guess_immutable_sampler(
+                        device,
+                        name.as_deref().expect("invalid binding name"),
+                    )?,
                 ),
                 DescriptorType::InputAttachment(attachment) => {
                     DescriptorInfo::InputAttachment(binding_count, *attachment)
@@ -433,7 +441,7 @@ impl Shader {
             );
         }
 
-        res
+        Ok(res)
     }
 
     pub fn merge_descriptor_bindings(
@@ -794,16 +802,7 @@ impl ShaderCode for &[u8] {
 
 impl ShaderCode for &[u32] {
     fn into_vec(self) -> Vec<u8> {
-        pub fn into_u8_slice<T>(t: &[T]) -> &[u8]
-        where
-            T: Sized,
-        {
-            use std::{mem::size_of, slice::from_raw_parts};
-
-            unsafe { from_raw_parts(t.as_ptr() as *const _, t.len() * size_of::<T>()) }
-        }
-
-        into_u8_slice(self).into_vec()
+        cast_slice(self).to_vec()
     }
 }
 
@@ -838,3 +837,40 @@ impl SpecializationInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::mem::size_of};
+
+    const CODE: [u32; 5] = [0x0723_0203, 0x0001_0000, 0x0008_000a, 0x0000_0006, 0];
+
+    fn words(data: &[u8]) -> Vec<u32> {
+        data.chunks_exact(size_of::<u32>())
+            .map(|word| u32::from_ne_bytes(word.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn shader_code_u32_slice() {
+        let data = CODE.as_slice().into_vec();
+
+        assert_eq!(data.len(), CODE.len() * size_of::<u32>());
+        assert_eq!(words(&data), CODE);
+    }
+
+    #[test]
+    fn shader_code_u32_vec() {
+        let data = CODE.to_vec().into_vec();
+
+        assert_eq!(data.len(), CODE.len() * size_of::<u32>());
+        assert_eq!(words(&data), CODE);
+    }
+
+    #[test]
+    fn shader_code_u8() {
+        let bytes = CODE.as_slice().into_vec();
+
+        assert_eq!(bytes.as_slice().into_vec(), bytes);
+        assert_eq!(bytes.clone().into_vec(), bytes);
+    }
+}
@@ -155,7 +155,12 @@ impl Swapchain {
                 .unwrap()
                 .queue_present(*self.device.queue, &present_info)
             {
-                Ok(_) => (),
+                Ok(suboptimal) => {
+                    // Handled in the next frame
+                    if suboptimal {
+                        self.suboptimal = true;
+                    }
+                }
                 Err(err)
                     if err == vk::Result::ERROR_DEVICE_LOST
                         || err == vk::Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT
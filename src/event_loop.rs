@@ -1,7 +1,7 @@
 use {
     super::{
         display::{Display, DisplayError},
-        driver::{Device, Driver, DriverConfigBuilder, DriverError},
+        driver::{Device, Driver, DriverConfigBuilder, DriverError, SwapchainError},
         frame::FrameContext,
         graph::ResolverPool,
         pool::hash::HashPool,
@@ -11,6 +11,7 @@ use {
         fmt::{Debug, Formatter},
         mem::take,
         sync::Arc,
+        thread::sleep,
         time::{Duration, Instant},
     },
     winit::{
@@ -125,7 +126,21 @@ impl EventLoop {
                 dt_filtered = dt_filtered + (dt_raw - dt_filtered) / 10.0;
             };
 
-            let (swapchain, mut render_graph) = self.display.acquire_next_image()?;
+            // A minimized window has no area to render into and cannot create a swapchain, so we
+            // skip this frame; events are kept for the next frame which is drawn
+            if self.width() == 0 || self.height() == 0 {
+                sleep(Duration::from_secs_f32(1.0 / refresh_rate));
+                continue;
+            }
+
+            let (swapchain, mut render_graph) = match self.display.acquire_next_image() {
+                Err(SwapchainError::Suboptimal) => {
+                    // The swapchain is out of date (usually after a resize) and will be recreated
+                    // when the next frame is acquired; events are kept for that frame
+                    continue;
+                }
+                res => res?,
+            };
 
             frame_fn(FrameContext {
                 device: &self.device,
@@ -235,14 +250,25 @@ impl EventLoopBuilder {
 
     /// Enables Vulkan graphics debugging layers.
     ///
-    /// _NOTE:_ Any valdation warnings or errors will cause the current thread to park itself after
-    /// describing the error using the `log` crate. This makes it easy to attach a debugger and see
-    /// what is causing the issue directly.
+    /// Validation messages are described using the `log` crate. See
+    /// [`park_on_validation_error`](Self::park_on_validation_error) to stop on errors.
     pub fn debug(mut self, debug: bool) -> Self {
         self.driver_cfg = self.driver_cfg.debug(debug);
         self
     }
 
+    /// Causes any validation errors to park the current thread after describing the error using
+    /// the `log` crate. This makes it easy to attach a debugger and see what is causing the issue
+    /// directly.
+    ///
+    /// Has no effect unless [`debug`](Self::debug) is enabled.
+    pub fn park_on_validation_error(mut self, park_on_validation_error: bool) -> Self {
+        self.driver_cfg = self
+            .driver_cfg
+            .park_on_validation_error(park_on_validation_error);
+        self
+    }
+
     /// Returns the primary monitor of the system.
     ///
     /// Returns `None` if it can't identify any monitor as a primary one.
@@ -24,9 +24,9 @@ use {
     self::{binding::Binding, edge::Edge, info::Information, node::Node},
     crate::driver::{
         buffer_copy_subresources, buffer_image_copy_subresource, format_aspect_mask,
-        is_write_access, BufferSubresource, ComputePipeline, DepthStencilMode,
-        DescriptorBindingMap, Device, GraphicPipeline, ImageSubresource, ImageType,
-        PipelineDescriptorInfo, RayTracePipeline, SampleCount,
+        format_texel_block_size, image_access_layout, is_write_access, BufferSubresource,
+        ComputePipeline, DepthStencilMode, DescriptorBindingMap, Device, GraphicPipeline,
+        ImageSubresource, ImageType, PipelineDescriptorInfo, RayTracePipeline, SampleCount,
     },
     ash::vk,
     std::{
@@ -58,6 +58,7 @@ struct Area {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 struct Attachment {
     aspect_mask: vk::ImageAspectFlags,
+    base_array_layer: u32,
     fmt: vk::Format,
     sample_count: SampleCount,
     target: NodeIndex,
@@ -76,7 +77,9 @@ impl Attachment {
     }
 
     fn are_identical(lhs: Self, rhs: Self) -> bool {
-        lhs.fmt == rhs.fmt && lhs.sample_count == rhs.sample_count
+        lhs.fmt == rhs.fmt
+            && lhs.sample_count == rhs.sample_count
+            && lhs.base_array_layer == rhs.base_array_layer
     }
 }
 
@@ -131,6 +134,7 @@ impl AttachmentMap {
         &mut self,
         attachment: AttachmentIndex,
         aspect_mask: vk::ImageAspectFlags,
+        base_array_layer: u32,
         fmt: vk::Format,
         sample_count: SampleCount,
         target: NodeIndex,
@@ -146,6 +150,7 @@ impl AttachmentMap {
             &mut self.attached[attachment as usize],
             Attachment {
                 aspect_mask,
+                base_array_layer,
                 fmt,
                 sample_count,
                 target,
@@ -183,6 +188,7 @@ impl AttachmentMap {
         &mut self,
         attachment: AttachmentIndex,
         aspect_mask: vk::ImageAspectFlags,
+        base_array_layer: u32,
         fmt: vk::Format,
         sample_count: SampleCount,
         target: NodeIndex,
@@ -199,6 +205,7 @@ impl AttachmentMap {
             &mut self.attached[attachment as usize],
             Attachment {
                 aspect_mask,
+                base_array_layer,
                 fmt,
                 sample_count,
                 target,
@@ -207,9 +214,84 @@ impl AttachmentMap {
     }
 }
 
+/// An RGBA color value, as used when clearing images.
+///
+/// The components are passed to Vulkan as-is: images with an `_SRGB` format expect linear values
+/// (the hardware encodes them) while `_UNORM` images, such as the swapchain, store the values
+/// directly. Use [`Color::srgb`] or [`Color::to_linear`] to convert between the two spaces.
 #[derive(Clone, Copy, Debug)]
 pub struct Color(pub [f32; 4]);
 
+impl Color {
+    /// Creates a color from linear components.
+    pub const fn linear(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self([r, g, b, a])
+    }
+
+    /// Linearly interpolates between this color and `other` by `t`, where `0.0` returns this color
+    /// and `1.0` returns `other`.
+    ///
+    /// Components are interpolated as-is, which is correct for colors in linear space. Use
+    /// [`Color::lerp_srgb`] for sRGB-encoded colors.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let [r0, g0, b0, a0] = self.0;
+        let [r1, g1, b1, a1] = other.0;
+
+        Self([
+            r0 + (r1 - r0) * t,
+            g0 + (g1 - g0) * t,
+            b0 + (b1 - b0) * t,
+            a0 + (a1 - a0) * t,
+        ])
+    }
+
+    /// Linearly interpolates between two sRGB-encoded colors by `t`, returning an sRGB-encoded
+    /// color.
+    ///
+    /// The colors are blended in linear space, which avoids the dark and muddy midpoints of
+    /// interpolating the encoded values directly.
+    pub fn lerp_srgb(self, other: Self, t: f32) -> Self {
+        self.to_linear().lerp(other.to_linear(), t).to_srgb()
+    }
+
+    /// Creates a color from sRGB-encoded components, converting them into linear space.
+    ///
+    /// Alpha is always linear and is not converted.
+    pub fn srgb(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self([r, g, b, a]).to_linear()
+    }
+
+    /// Converts sRGB-encoded color components into linear space.
+    pub fn to_linear(self) -> Self {
+        fn to_linear(c: f32) -> f32 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        let [r, g, b, a] = self.0;
+
+        Self([to_linear(r), to_linear(g), to_linear(b), a])
+    }
+
+    /// Converts linear color components into sRGB-encoded space.
+    pub fn to_srgb(self) -> Self {
+        fn to_srgb(c: f32) -> f32 {
+            if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+
+        let [r, g, b, a] = self.0;
+
+        Self([to_srgb(r), to_srgb(g), to_srgb(b), a])
+    }
+}
+
 impl From<[f32; 4]> for Color {
     fn from(color: [f32; 4]) -> Self {
         Self(color)
@@ -444,6 +526,27 @@ impl Pass {
     }
 }
 
+/// A comparable snapshot of one execution within a [`RecordedPass`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedExecution {
+    /// The index of each accessed node along with the first and last access of that node.
+    pub accesses: Vec<(usize, [AccessType; 2])>,
+
+    /// The bind point of the pipeline, or `None` if the execution records a command buffer
+    /// directly.
+    pub bind_point: Option<vk::PipelineBindPoint>,
+
+    /// The debugging name of the pipeline, if it has one.
+    pub pipeline_name: Option<String>,
+}
+
+/// A comparable snapshot of a pass, as returned by [`RenderGraph::recorded_passes`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedPass {
+    pub execs: Vec<RecordedExecution>,
+    pub name: String,
+}
+
 #[derive(Debug)]
 pub struct RenderGraph {
     bindings: Vec<Binding>,
@@ -566,7 +669,7 @@ impl RenderGraph {
                     *bindings[src_node],
                     vk::ImageLayout::TRANSFER_SRC_OPTIMAL,
                     *bindings[dst_node],
-                    vk::ImageLayout::TRANSFER_SRC_OPTIMAL,
+                    vk::ImageLayout::TRANSFER_DST_OPTIMAL,
                     &regions,
                     filter,
                 );
@@ -765,6 +868,13 @@ impl RenderGraph {
             .submit_pass()
     }
 
+    /// Copies the first mip level of one image into another.
+    ///
+    /// The first array layer is copied, or all six faces for cube and cube array images.
+    ///
+    /// Texel data is copied without conversion between formats with the same texel size. Formats
+    /// with different texel sizes, such as `R16G16B16A16_SFLOAT` and `R8G8B8A8_UNORM`, are instead
+    /// converted using a nearest-filtered blit and so must support `BLIT_SRC` and `BLIT_DST`.
     pub fn copy_image(
         &mut self,
         src_node: impl Into<AnyImageNode>,
@@ -822,6 +932,9 @@ impl RenderGraph {
         self.copy_image_regions(src_node, dst_node, from_ref(region))
     }
 
+    /// Copies regions of one image into another, converting formats using a blit if required.
+    ///
+    /// See [`RenderGraph::copy_image`].
     pub fn copy_image_regions(
         &mut self,
         src_node: impl Into<AnyImageNode>,
@@ -834,6 +947,19 @@ impl RenderGraph {
         let dst_access_range = self.node_info(dst_node).default_view_info();
         let regions = regions.into();
 
+        // Formats with different texel sizes cannot be copied, but a blit converts between them
+        if matches!(
+            (
+                format_texel_block_size(src_access_range.fmt),
+                format_texel_block_size(dst_access_range.fmt),
+            ),
+            (Some(src_size), Some(dst_size)) if src_size != dst_size
+        ) {
+            let regions = regions.iter().map(image_copy_blit).collect::<Box<[_]>>();
+
+            return self.blit_image_regions(src_node, dst_node, regions, vk::Filter::NEAREST);
+        }
+
         self.begin_pass("copy image")
             .access_node_subrange(src_node, AccessType::TransferRead, src_access_range)
             .access_node_subrange(dst_node, AccessType::TransferWrite, dst_access_range)
@@ -921,6 +1047,114 @@ impl RenderGraph {
             .submit_pass()
     }
 
+    /// Returns a textual description of the passes of this graph, the node accesses of each pass,
+    /// and the access transitions (including image layouts) which the resolver will insert
+    /// barriers for.
+    ///
+    /// This is intended to help diagnose synchronization issues; the output format may change.
+    pub fn debug_dump(&self) -> String {
+        use std::fmt::Write;
+
+        let mut res = String::new();
+        let mut prev_accesses = BTreeMap::new();
+
+        for (pass_idx, pass) in self.passes.iter().enumerate() {
+            writeln!(res, "pass {pass_idx} \"{}\"", pass.name).unwrap();
+
+            for (exec_idx, exec) in pass.execs.iter().enumerate() {
+                // The final execution of each pass is a placeholder with no function
+                if exec.func.is_none() {
+                    continue;
+                }
+
+                let pipeline = match &exec.pipeline {
+                    Some(ExecutionPipeline::Compute(_)) => "compute",
+                    Some(ExecutionPipeline::Graphic(_)) => "graphic",
+                    Some(ExecutionPipeline::RayTrace(_)) => "ray trace",
+                    None => "command buffer",
+                };
+
+                writeln!(res, "  exec {exec_idx} ({pipeline})").unwrap();
+
+                for (node_idx, [early, late]) in &exec.accesses {
+                    let binding = &self.bindings[*node_idx];
+                    let prev_access = prev_accesses
+                        .insert(*node_idx, late.access)
+                        .unwrap_or(AccessType::Nothing);
+
+                    write!(res, "    node {node_idx} ").unwrap();
+
+                    if let Some(image) = binding.as_driver_image() {
+                        write!(
+                            res,
+                            "image {image:?}: {prev_access:?} ({:?}) -> {:?} ({:?})",
+                            image_access_layout(prev_access),
+                            early.access,
+                            image_access_layout(early.access),
+                        )
+                        .unwrap();
+                    } else if let Some(buffer) = binding.as_driver_buffer() {
+                        write!(
+                            res,
+                            "buffer {buffer:?}: {prev_access:?} -> {:?}",
+                            early.access
+                        )
+                        .unwrap();
+                    } else {
+                        write!(
+                            res,
+                            "acceleration structure: {prev_access:?} -> {:?}",
+                            early.access
+                        )
+                        .unwrap();
+                    }
+
+                    if late.access != early.access {
+                        write!(res, " then {:?}", late.access).unwrap();
+                    }
+
+                    res.push('\n');
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Returns a snapshot of the passes recorded so far which may be compared with an expected
+    /// list, allowing tests to check that a frame recorded exactly the intended work.
+    ///
+    /// Node indices are assigned in binding order and so are stable between runs. Anything
+    /// recorded inside a closure, such as push constants or draw calls, is not captured.
+    pub fn recorded_passes(&self) -> Vec<RecordedPass> {
+        self.passes
+            .iter()
+            .map(|pass| RecordedPass {
+                execs: pass
+                    .execs
+                    .iter()
+                    .filter(|exec| exec.func.is_some())
+                    .map(|exec| RecordedExecution {
+                        accesses: exec
+                            .accesses
+                            .iter()
+                            .map(|(node_idx, [early, late])| {
+                                (*node_idx, [early.access, late.access])
+                            })
+                            .collect(),
+                        bind_point: exec.pipeline.as_ref().map(ExecutionPipeline::bind_point),
+                        pipeline_name: exec.pipeline.as_ref().and_then(|pipeline| match pipeline {
+                            ExecutionPipeline::Compute(pipeline) => pipeline.info.name.clone(),
+                            ExecutionPipeline::Graphic(pipeline) => pipeline.info.name.clone(),
+                            ExecutionPipeline::RayTrace(pipeline) => pipeline.info.name.clone(),
+                        }),
+                    })
+                    .collect(),
+                name: pass.name.clone(),
+            })
+            .collect()
+    }
+
     pub fn fill_buffer(&mut self, buffer_node: impl Into<AnyBufferNode>, data: u32) -> &mut Self {
         let buffer_node = buffer_node.into();
 
@@ -1096,3 +1330,158 @@ struct SubresourceAccess {
     access: AccessType,
     subresource: Option<Subresource>,
 }
+
+/// Returns a blit region which covers the same texels as a copy region.
+fn image_copy_blit(region: &vk::ImageCopy) -> vk::ImageBlit {
+    let end = |offset: vk::Offset3D| vk::Offset3D {
+        x: offset.x + region.extent.width as i32,
+        y: offset.y + region.extent.height as i32,
+        z: offset.z + region.extent.depth as i32,
+    };
+
+    vk::ImageBlit {
+        src_subresource: region.src_subresource,
+        src_offsets: [region.src_offset, end(region.src_offset)],
+        dst_subresource: region.dst_subresource,
+        dst_offsets: [region.dst_offset, end(region.dst_offset)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.0001;
+
+    fn assert_close(lhs: f32, rhs: f32) {
+        assert!((lhs - rhs).abs() < EPSILON, "{lhs} != {rhs}");
+    }
+
+    #[test]
+    fn color_lerp_srgb() {
+        let red = Color([1.0, 0.0, 0.0, 1.0]);
+        let green = Color([0.0, 1.0, 0.0, 1.0]);
+
+        // The naive sRGB midpoint is much darker than the true midpoint in linear space
+        let naive = red.lerp(green, 0.5).to_linear();
+        let blended = red.lerp_srgb(green, 0.5);
+        let linear = blended.to_linear();
+
+        assert_close(naive.0[0], 0.2140);
+        assert_close(naive.0[1], 0.2140);
+        assert_close(linear.0[0], 0.5);
+        assert_close(linear.0[1], 0.5);
+        assert_close(linear.0[2], 0.0);
+        assert_close(linear.0[3], 1.0);
+        assert_close(blended.0[0], 0.7354);
+        assert!(linear.0[0] > naive.0[0]);
+    }
+
+    #[test]
+    fn color_srgb_round_trip() {
+        let linear = Color::srgb(0.5, 0.5, 0.5, 0.5);
+
+        assert_close(linear.0[0], 0.2140);
+        assert_close(linear.0[3], 0.5);
+
+        let srgb = linear.to_srgb();
+
+        for c in srgb.0 {
+            assert_close(c, 0.5);
+        }
+    }
+
+    #[test]
+    fn image_copy_blit_covers_copy() {
+        let subresource = vk::ImageSubresourceLayers {
+            aspect_mask: vk::ImageAspectFlags::COLOR,
+            mip_level: 0,
+            base_array_layer: 0,
+            layer_count: 1,
+        };
+        let blit = image_copy_blit(&vk::ImageCopy {
+            src_subresource: subresource,
+            src_offset: vk::Offset3D { x: 1, y: 2, z: 0 },
+            dst_subresource: subresource,
+            dst_offset: vk::Offset3D { x: 3, y: 4, z: 0 },
+            extent: vk::Extent3D {
+                width: 10,
+                height: 20,
+                depth: 1,
+            },
+        });
+
+        assert_eq!(blit.src_offsets[0], vk::Offset3D { x: 1, y: 2, z: 0 });
+        assert_eq!(blit.src_offsets[1], vk::Offset3D { x: 11, y: 22, z: 1 });
+        assert_eq!(blit.dst_offsets[0], vk::Offset3D { x: 3, y: 4, z: 0 });
+        assert_eq!(blit.dst_offsets[1], vk::Offset3D { x: 13, y: 24, z: 1 });
+        assert_eq!(blit.src_subresource.layer_count, 1);
+        assert_eq!(blit.dst_subresource.layer_count, 1);
+    }
+
+    #[test]
+    fn debug_dump_execs_without_accesses() {
+        let mut graph = RenderGraph::new();
+        graph
+            .begin_pass("a")
+            .record_cmd_buf(|_, _, _| {})
+            .record_cmd_buf(|_, _, _| {})
+            .submit_pass();
+
+        let dump = graph.debug_dump();
+
+        assert!(dump.contains("  exec 0 (command buffer)\n"), "{dump}");
+        assert!(dump.contains("  exec 1 (command buffer)\n"), "{dump}");
+        assert!(!dump.contains("  exec 2 "), "{dump}");
+    }
+
+    #[test]
+    fn recorded_passes_execs_without_accesses() {
+        let mut graph = RenderGraph::new();
+        graph
+            .begin_pass("a")
+            .record_cmd_buf(|_, _, _| {})
+            .record_cmd_buf(|_, _, _| {})
+            .submit_pass();
+
+        let exec = RecordedExecution {
+            accesses: vec![],
+            bind_point: None,
+            pipeline_name: None,
+        };
+
+        assert_eq!(
+            graph.recorded_passes(),
+            [RecordedPass {
+                execs: vec![exec.clone(), exec],
+                name: "a".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn color_to_linear_breakpoint() {
+        // Below 0.04045 the curve is linear; above it is the gamma curve
+        let linear = Color([0.04, 0.05, 0.0, 1.0]).to_linear();
+
+        assert_close(linear.0[0], 0.003096);
+        assert_close(linear.0[1], 0.003936);
+        assert_close(linear.0[2], 0.0);
+        assert_close(linear.0[3], 1.0);
+    }
+
+    #[test]
+    fn color_to_srgb_breakpoint() {
+        // Below 0.0031308 the curve is linear; above it is the gamma curve
+        let srgb = Color([0.003, 0.004, 1.0, 1.0]).to_srgb();
+
+        assert_close(srgb.0[0], 0.03876);
+        assert_close(srgb.0[1], 0.05071);
+        assert_close(srgb.0[2], 1.0);
+        assert_close(srgb.0[3], 1.0);
+
+        for c in [0.003, 0.004] {
+            assert_close(Color([c; 4]).to_srgb().to_linear().0[0], c);
+        }
+    }
+}
@@ -314,6 +314,45 @@ impl<'a> Index<AnyImageNode> for Bindings<'a> {
     }
 }
 
+/// Checks that push constant data fits within the pipeline layout ranges and the device limit.
+///
+/// Data which does not exactly overlap the declared ranges is a programmer error and would
+/// otherwise be silently truncated.
+#[cfg(debug_assertions)]
+fn assert_push_constants(
+    max_push_constants_size: u32,
+    push_consts: &[vk::PushConstantRange],
+    offset: u32,
+    data: &[u8],
+) {
+    let data_end = offset + data.len() as u32;
+
+    assert!(
+        data_end <= max_push_constants_size,
+        "push constants {offset}..{data_end} exceed device limit of {max_push_constants_size} bytes"
+    );
+    assert!(
+        !push_consts.is_empty(),
+        "push constants {offset}..{data_end} pushed to pipeline without push constants"
+    );
+
+    let range_start = push_consts
+        .iter()
+        .map(|push_const| push_const.offset)
+        .min()
+        .unwrap_or_default();
+    let range_end = push_consts
+        .iter()
+        .map(|push_const| push_const.offset + push_const.size)
+        .max()
+        .unwrap_or_default();
+
+    assert!(
+        offset >= range_start && data_end <= range_end,
+        "push constants {offset}..{data_end} outside of pipeline range {range_start}..{range_end}"
+    );
+}
+
 pub struct Compute<'a> {
     bindings: Bindings<'a>,
     cmd_buf: vk::CommandBuffer,
@@ -375,6 +414,18 @@ impl<'a> Compute<'a> {
     }
 
     pub fn push_constants_offset(&self, offset: u32, data: &[u8]) -> &Self {
+        #[cfg(debug_assertions)]
+        assert_push_constants(
+            self.device
+                .physical_device
+                .props
+                .limits
+                .max_push_constants_size,
+            self.pipeline.push_constants.as_slice(),
+            offset,
+            data,
+        );
+
         if let Some(push_const) = &self.pipeline.push_constants {
             // Determine the range of the overall pipline push constants which overlap with `data`
             let push_const_end = push_const.offset + push_const.size;
@@ -395,7 +446,7 @@ impl<'a> Compute<'a> {
                         self.cmd_buf,
                         self.pipeline.layout,
                         vk::ShaderStageFlags::COMPUTE,
-                        push_const.offset,
+                        start,
                         &data[(start - offset) as usize..(end - offset) as usize],
                     );
                 }
@@ -649,6 +700,18 @@ impl<'a> Draw<'a> {
     }
 
     pub fn push_constants_offset(&self, offset: u32, data: &[u8]) -> &Self {
+        #[cfg(debug_assertions)]
+        assert_push_constants(
+            self.device
+                .physical_device
+                .props
+                .limits
+                .max_push_constants_size,
+            &self.pipeline.push_constants,
+            offset,
+            data,
+        );
+
         for push_const in &self.pipeline.push_constants {
             // Determine the range of the overall pipline push constants which overlap with `data`
             let push_const_end = push_const.offset + push_const.size;
@@ -995,6 +1058,7 @@ impl<'a, T> PipelinePassRef<'a, T>
 where
     T: Access,
 {
+    /// Binds a node to the given shader descriptor using an explicit access type.
     pub fn access_descriptor<N>(
         self,
         descriptor: impl Into<Descriptor>,
@@ -1120,6 +1184,13 @@ where
         );
     }
 
+    /// Binds an image, buffer or acceleration structure node to the given shader descriptor for
+    /// reading, using the default read access of this pipeline type.
+    ///
+    /// Storage buffers may be bound using this function. Uniform buffers should instead be bound
+    /// using [`Self::access_descriptor`] with a uniform buffer access type (for example
+    /// `AccessType::ComputeShaderReadUniformBuffer`) so that the correct memory dependency is
+    /// used.
     pub fn read_descriptor<N>(self, descriptor: impl Into<Descriptor>, node: N) -> Self
     where
         N: Information,
@@ -1313,6 +1384,9 @@ impl<'a> PipelinePassRef<'a, GraphicPipeline> {
 
     /// Specifies `VK_ATTACHMENT_LOAD_OP_LOAD` and `VK_ATTACHMENT_STORE_OP_STORE` for the render
     /// pass attachment.
+    ///
+    /// The `base_array_layer` of `view_info` selects which layer of an array or cube image is
+    /// rendered to.
     pub fn attach_color_as(
         self,
         attachment: AttachmentIndex,
@@ -1342,6 +1416,9 @@ impl<'a> PipelinePassRef<'a, GraphicPipeline> {
 
     /// Specifies `VK_ATTACHMENT_LOAD_OP_LOAD` and `VK_ATTACHMENT_STORE_OP_STORE` for the render
     /// pass attachment.
+    ///
+    /// The `base_array_layer` of `image_view_info` selects which layer of an array or cube image
+    /// is rendered to, such as one cascade of a shadow map array.
     pub fn attach_depth_stencil_as(
         self,
         attachment: AttachmentIndex,
@@ -1478,6 +1555,7 @@ impl<'a> PipelinePassRef<'a, GraphicPipeline> {
                 exec.loads.insert_color(
                     attachment,
                     image_view_info.aspect_mask,
+                    image_view_info.base_array_layer,
                     image_view_info.fmt,
                     sample_count,
                     node_idx,
@@ -1580,6 +1658,7 @@ impl<'a> PipelinePassRef<'a, GraphicPipeline> {
                 exec.loads.set_depth_stencil(
                     attachment,
                     image_view_info.aspect_mask,
+                    image_view_info.base_array_layer,
                     image_view_info.fmt,
                     sample_count,
                     node_idx,
@@ -1720,6 +1799,7 @@ impl<'a> PipelinePassRef<'a, GraphicPipeline> {
                 exec.resolves.insert_color(
                     attachment,
                     image_view_info.aspect_mask,
+                    image_view_info.base_array_layer,
                     image_view_info.fmt,
                     sample_count,
                     node_idx,
@@ -1820,6 +1900,7 @@ impl<'a> PipelinePassRef<'a, GraphicPipeline> {
                 exec.resolves.set_depth_stencil(
                     attachment,
                     image_view_info.aspect_mask,
+                    image_view_info.base_array_layer,
                     image_view_info.fmt,
                     sample_count,
                     node_idx,
@@ -1970,6 +2051,7 @@ impl<'a> PipelinePassRef<'a, GraphicPipeline> {
                 exec.stores.insert_color(
                     attachment,
                     image_view_info.aspect_mask,
+                    image_view_info.base_array_layer,
                     image_view_info.fmt,
                     sample_count,
                     node_idx,
@@ -2070,6 +2152,7 @@ impl<'a> PipelinePassRef<'a, GraphicPipeline> {
                 exec.stores.set_depth_stencil(
                     attachment,
                     image_view_info.aspect_mask,
+                    image_view_info.base_array_layer,
                     image_view_info.fmt,
                     sample_count,
                     node_idx,
@@ -2162,6 +2245,18 @@ impl<'a> RayTrace<'a> {
         self.push_constants_offset(0, data)
     }
     pub fn push_constants_offset(&self, offset: u32, data: &[u8]) -> &Self {
+        #[cfg(debug_assertions)]
+        assert_push_constants(
+            self.device
+                .physical_device
+                .props
+                .limits
+                .max_push_constants_size,
+            &self.pipeline.push_constants,
+            offset,
+            data,
+        );
+
         for push_const in &self.pipeline.push_constants {
             let push_const_end = push_const.offset + push_const.size;
             let data_end = offset + data.len() as u32;
@@ -2255,3 +2350,49 @@ impl<'a> RayTrace<'a> {
         self
     }
 }
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::*;
+
+    const MAX_PUSH_CONSTANTS_SIZE: u32 = 128;
+
+    fn push_consts(offset: u32, size: u32) -> [vk::PushConstantRange; 1] {
+        [vk::PushConstantRange {
+            stage_flags: vk::ShaderStageFlags::COMPUTE,
+            offset,
+            size,
+        }]
+    }
+
+    #[test]
+    fn push_constants_within_range() {
+        assert_push_constants(MAX_PUSH_CONSTANTS_SIZE, &push_consts(0, 32), 0, &[0; 32]);
+        assert_push_constants(MAX_PUSH_CONSTANTS_SIZE, &push_consts(0, 32), 16, &[0; 16]);
+        assert_push_constants(MAX_PUSH_CONSTANTS_SIZE, &push_consts(16, 16), 16, &[0; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceed device limit")]
+    fn push_constants_exceed_device_limit() {
+        assert_push_constants(MAX_PUSH_CONSTANTS_SIZE, &push_consts(0, 256), 0, &[0; 132]);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of pipeline range")]
+    fn push_constants_past_range_end() {
+        assert_push_constants(MAX_PUSH_CONSTANTS_SIZE, &push_consts(0, 32), 0, &[0; 36]);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of pipeline range")]
+    fn push_constants_before_range_start() {
+        assert_push_constants(MAX_PUSH_CONSTANTS_SIZE, &push_consts(16, 16), 12, &[0; 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pipeline without push constants")]
+    fn push_constants_without_range() {
+        assert_push_constants(MAX_PUSH_CONSTANTS_SIZE, &[], 0, &[0; 4]);
+    }
+}
@@ -9,8 +9,8 @@ use {
             pipeline_stage_access_flags, AccelerationStructure, AttachmentInfo, AttachmentRef,
             Buffer, CommandBuffer, DepthStencilMode, DescriptorBinding, DescriptorInfo,
             DescriptorPool, DescriptorPoolInfo, DescriptorSet, Device, DriverError, FramebufferKey,
-            FramebufferKeyAttachment, Image, ImageViewInfo, Queue, QueueFamily, RenderPass,
-            RenderPassInfo, SampleCount, SubpassDependency, SubpassInfo,
+            FramebufferKeyAttachment, Image, ImageType, ImageViewInfo, Queue, QueueFamily,
+            RenderPass, RenderPassInfo, SampleCount, SubpassDependency, SubpassInfo,
         },
         pool::{hash::HashPool, lazy::LazyPool, Lease, Pool},
     },
@@ -219,14 +219,21 @@ impl Resolver {
                         let image = self.graph.bindings[attachment.target]
                             .as_driver_image()
                             .unwrap();
+                        // Attachments are always single-layer 2D views, which may select any
+                        // layer of an array or cube image
                         let view_info = ImageViewInfo {
                             array_layer_count: Some(1),
                             aspect_mask: attachment.aspect_mask,
-                            base_array_layer: 0,
+                            base_array_layer: attachment.base_array_layer,
                             base_mip_level: 0,
                             fmt: attachment.fmt,
                             mip_level_count: Some(1),
-                            ty: image.info.ty,
+                            ty: match image.info.ty {
+                                ImageType::Cube
+                                | ImageType::CubeArray
+                                | ImageType::TextureArray2D => ImageType::Texture2D,
+                                ty => ty,
+                            },
                         };
 
                         trace!("attachment {attachment_idx}: {image:?}");
@@ -251,7 +258,7 @@ impl Resolver {
                     usage: image.info.usage,
                     extent_x: image.info.width,
                     extent_y: image.info.height,
-                    layer_count: image.info.array_elements,
+                    layer_count: 1,
                     view_fmts: pass
                         .execs
                         .iter()